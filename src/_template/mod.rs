@@ -1,8 +1,48 @@
 // https://adventofcode.com/2023/day/X
 
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part1::run();
+pub struct DayX;
+
+impl Solution for DayX {
+    type Input = Vec<String>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: None,
+        part2: None,
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    #[ignore = "paste the example into example.txt and its answer into EXAMPLES"]
+    fn test_part1_examples() {
+        check_examples::<DayX>(1);
+    }
+
+    #[test]
+    #[ignore = "paste the example into example.txt and its answer into EXAMPLES"]
+    fn test_part2_examples() {
+        check_examples::<DayX>(2);
+    }
 }
@@ -1,5 +1,3 @@
-use crate::common::get_input;
-
-pub fn run() {
-    let input = get_input("src/dayX/input0.txt");
+pub fn solve(_input: &[String]) -> u64 {
+    0
 }
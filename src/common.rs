@@ -1,36 +0,0 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-pub fn read_lines<P>(filename: P) -> std::io::Result<std::io::Lines<std::io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(std::io::BufReader::new(file).lines())
-}
-
-pub fn get_input(filename: &str) -> Vec<String> {
-    let mut result = Vec::new();
-    let path = Path::new(filename);
-    match read_lines(path) {
-        Ok(lines) => {
-            for line in lines {
-                match line {
-                    Ok(line) => {
-                        result.push(line);
-                    }
-                    Err(err) => {
-                        panic!("Cannot read line in {} - {}", path.display(), err);
-                    }
-                }
-            }
-        }
-        Err(err) => {
-            panic!("Cannot read file {} - {}", path.display(), err);
-        }
-    }
-    result
-}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{Answer, InputSet};
+
+/// Accepted answers keyed by day, part and input set, e.g. `answers["day9"]["part1"]["real"]`.
+type AnswerTable = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+/// How a fresh answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Nothing has been recorded for this day, part and input set.
+    Unknown,
+    Correct,
+    Wrong {
+        expected: String,
+    },
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Unknown => write!(f, "unverified"),
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+        }
+    }
+}
+
+/// A TOML file of accepted answers that runs are checked against.
+#[derive(Debug)]
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: AnswerTable,
+}
+
+impl AnswerStore {
+    /// Load the store at `path`; a missing file is an empty store.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let path = path.into();
+        let answers = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|err| format!("cannot parse {} - {}", path.display(), err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => AnswerTable::new(),
+            Err(err) => return Err(format!("cannot read {} - {}", path.display(), err)),
+        };
+        Ok(Self { path, answers })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Answers piped in on stdin can't be told apart, so they have no key.
+    fn keys(day: u8, part: u8, input: &InputSet) -> Option<(String, String, String)> {
+        match input {
+            InputSet::Stdin => None,
+            _ => Some((
+                format!("day{day}"),
+                format!("part{part}"),
+                input.to_string(),
+            )),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &InputSet) -> Option<&str> {
+        let (day, part, input) = Self::keys(day, part, input)?;
+        self.answers
+            .get(&day)?
+            .get(&part)?
+            .get(&input)
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: u8, input: &InputSet, answer: &Answer) -> Verdict {
+        match self.get(day, part, input) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Record `answer` as accepted, returning the answer it replaced, if any.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        input: &InputSet,
+        answer: &Answer,
+    ) -> Result<Option<String>, String> {
+        let (day, part, input) = Self::keys(day, part, input)
+            .ok_or_else(|| "cannot record an answer for stdin input".to_string())?;
+        Ok(self
+            .answers
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .or_default()
+            .insert(input, answer.to_string()))
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let text = toml::to_string_pretty(&self.answers)
+            .map_err(|err| format!("cannot serialize answers - {err}"))?;
+        fs::write(&self.path, text)
+            .map_err(|err| format!("cannot write {} - {}", self.path.display(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_check() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut store = AnswerStore::load(&path).unwrap();
+        assert_eq!(
+            store.check(9, 1, &InputSet::Example, &Answer::Int(114)),
+            Verdict::Unknown
+        );
+
+        store
+            .record(9, 1, &InputSet::Example, &Answer::Int(114))
+            .unwrap();
+        store.save().unwrap();
+
+        let store = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            store.check(9, 1, &InputSet::Example, &Answer::Int(114)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            store.check(9, 1, &InputSet::Example, &Answer::Int(113)),
+            Verdict::Wrong {
+                expected: "114".to_string()
+            }
+        );
+        assert_eq!(
+            store.check(9, 1, &InputSet::Real, &Answer::Int(114)),
+            Verdict::Unknown
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::{InputResult, Solution};
+
+/// How long one run spent parsing the input and solving the part.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Parse `lines` and solve the given part of puzzle `S`, timing the two steps separately.
+pub fn sample<S: Solution>(part: u8, lines: Vec<String>) -> InputResult<Sample> {
+    let start = Instant::now();
+    let input = S::parse(lines)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(&input),
+        2 => S::part2(&input),
+        _ => panic!("Invalid part {part}"),
+    };
+    // Keep the work behind the answer from being optimized away.
+    hint::black_box(answer);
+    let solve = start.elapsed();
+
+    Ok(Sample { parse, solve })
+}
+
+/// Summary statistics over repeated runs, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn new(durations: &[Duration]) -> Self {
+        assert!(!durations.is_empty(), "Need at least one duration");
+        let mut ns = durations
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        ns.sort_by(|a, b| a.total_cmp(b));
+
+        let n = ns.len() as f64;
+        let mean = ns.iter().sum::<f64>() / n;
+        let median = if ns.len() % 2 == 0 {
+            (ns[ns.len() / 2 - 1] + ns[ns.len() / 2]) / 2.0
+        } else {
+            ns[ns.len() / 2]
+        };
+        let variance = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        Self {
+            mean_ns: mean.round() as u64,
+            median_ns: median.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.2?} ± {:.2?} (median {:.2?})",
+            Duration::from_nanos(self.mean_ns),
+            Duration::from_nanos(self.stddev_ns),
+            self.median()
+        )
+    }
+}
+
+/// Timings of one day's part over repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PartBench {
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+impl PartBench {
+    pub fn new(samples: &[Sample]) -> Self {
+        let parse = samples.iter().map(|s| s.parse).collect::<Vec<_>>();
+        let solve = samples.iter().map(|s| s.solve).collect::<Vec<_>>();
+        let total = samples
+            .iter()
+            .map(|s| s.parse + s.solve)
+            .collect::<Vec<_>>();
+        Self {
+            runs: samples.len(),
+            parse: Stats::new(&parse),
+            solve: Stats::new(&solve),
+            total: Stats::new(&total),
+        }
+    }
+
+    /// Relative change in median total time against `baseline`; positive is slower.
+    pub fn change_from(&self, baseline: &PartBench) -> f64 {
+        let old = baseline.total.median_ns.max(1) as f64;
+        (self.total.median_ns as f64 - old) / old
+    }
+}
+
+/// Saved benchmark results keyed by day, part and input set, like the answer store.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, BTreeMap<String, PartBench>>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {} - {}", path.display(), err))?;
+        toml::from_str(&text).map_err(|err| format!("cannot parse {} - {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string_pretty(self)
+            .map_err(|err| format!("cannot serialize benchmarks - {err}"))?;
+        fs::write(path, text).map_err(|err| format!("cannot write {} - {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&PartBench> {
+        self.0
+            .get(&format!("day{day}"))?
+            .get(&format!("part{part}"))?
+            .get(input)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, input: &str, bench: PartBench) {
+        self.0
+            .entry(format!("day{day}"))
+            .or_default()
+            .entry(format!("part{part}"))
+            .or_default()
+            .insert(input.to_string(), bench);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let durations = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::new(&durations),
+            Stats {
+                mean_ns: 5,
+                median_ns: 5,
+                stddev_ns: 2,
+            }
+        );
+        assert_eq!(Stats::new(&durations[..3]).median_ns, 4);
+    }
+}
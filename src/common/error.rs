@@ -0,0 +1,90 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Why a puzzle input could not be loaded or parsed.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file could not be opened or read.
+    Io { path: PathBuf, source: io::Error },
+    /// A token on one line could not be parsed. `line` and `column` are 1-based.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Every line parsed, but together they don't form a valid puzzle (e.g. a ragged grid).
+    Malformed { day: u8, message: String },
+}
+
+pub type InputResult<T> = Result<T, InputError>;
+
+impl InputError {
+    /// A parse error at the 0-based `line_idx` and `col_idx` of a day's input.
+    pub fn parse(day: u8, line_idx: usize, col_idx: usize, message: impl Into<String>) -> Self {
+        InputError::Parse {
+            day,
+            line: line_idx + 1,
+            column: col_idx + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Shift a parse error's line by `n`, for errors from parsing a block that starts `n` lines in.
+    pub fn offset_lines(self, n: usize) -> Self {
+        match self {
+            InputError::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => InputError::Parse {
+                day,
+                line: line + n,
+                column,
+                message,
+            },
+            err => err,
+        }
+    }
+
+    pub fn malformed(day: u8, message: impl Into<String>) -> Self {
+        InputError::Malformed {
+            day,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io { path, source } => {
+                write!(f, "cannot read {} - {}", path.display(), source)
+            }
+            InputError::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "day {day} input, line {line}, column {column}: {message}"
+            ),
+            InputError::Malformed { day, message } => {
+                write!(f, "day {day} input is malformed: {message}")
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
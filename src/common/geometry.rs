@@ -0,0 +1,375 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::{NumCast, PrimInt, Signed};
+
+/// One of the four cardinal directions. Up is towards the first line of the input, so it
+/// decreases `y` (or `row`).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All four directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_cw(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_ccw(self) -> Self {
+        self.turn_cw().turn_cw().turn_cw()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_cw().turn_cw()
+    }
+
+    /// The `(x, y)` step taken when moving one cell this way.
+    pub fn delta(self) -> (isize, isize) {
+        use Direction::*;
+        match self {
+            Up => (0, -1),
+            Right => (1, 0),
+            Down => (0, 1),
+            Left => (-1, 0),
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+/// One of the four cardinal or four diagonal directions.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turn an eighth of a circle clockwise.
+    pub fn turn_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turn an eighth of a circle counter-clockwise.
+    pub fn turn_ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The `(x, y)` step taken when moving one cell this way.
+    pub fn delta(self) -> (isize, isize) {
+        use Direction8::*;
+        match self {
+            Up => (0, -1),
+            UpRight => (1, -1),
+            Right => (1, 0),
+            DownRight => (1, 1),
+            Down => (0, 1),
+            DownLeft => (-1, 1),
+            Left => (-1, 0),
+            UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+/// `|a - b|`, without overflowing for unsigned types.
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// `value` moved `delta` steps of `n`, or `None` if that leaves `T`'s range.
+fn checked_offset<T: PrimInt>(value: T, delta: isize, n: T) -> Option<T> {
+    let step = n.checked_mul(&<T as NumCast>::from(delta.unsigned_abs())?)?;
+    if delta < 0 {
+        value.checked_sub(&step)
+    } else {
+        value.checked_add(&step)
+    }
+}
+
+/// A point in the plane, with `y` growing downwards like the rows of an input grid.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: PrimInt> Point<T> {
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+        ))
+    }
+
+    /// The point `n` steps away in `direction`, or `None` if that leaves `T`'s range.
+    pub fn checked_step(self, direction: impl Into<Direction8>, n: T) -> Option<Self> {
+        let (dx, dy) = direction.into().delta();
+        Some(Self::new(
+            checked_offset(self.x, dx, n)?,
+            checked_offset(self.y, dy, n)?,
+        ))
+    }
+
+    /// The point `n` steps away in `direction`.
+    pub fn step_by(self, direction: impl Into<Direction8>, n: T) -> Self {
+        self.checked_step(direction, n)
+            .expect("Stepped outside the coordinate range")
+    }
+
+    /// The adjacent point in `direction`.
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self.step_by(direction, T::one())
+    }
+
+    /// The orthogonal neighbors that don't overflow, clockwise from up.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction, T::one()))
+    }
+
+    /// The orthogonal and diagonal neighbors that don't overflow, clockwise from up.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction, T::one()))
+    }
+
+    /// The direction of `other` if it is an orthogonal neighbor.
+    pub fn direction_to(self, other: Self) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| self.checked_step(direction, T::one()) == Some(other))
+    }
+}
+
+impl<T: PrimInt + Signed> Point<T> {
+    /// Rotate a quarter turn clockwise around the origin.
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate a quarter turn counter-clockwise around the origin.
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Self::new(self.x * n, self.y * n)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// A point in space.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: PrimInt> Point3<T> {
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+            self.z.checked_add(&other.z)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+            self.z.checked_sub(&other.z)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_cw().turn_ccw(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert_eq!(Direction::Left.turn_cw(), Direction::Up);
+        assert_eq!(Direction8::UpLeft.turn_cw(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_ccw(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+    }
+
+    #[test]
+    fn test_steps() {
+        let p = Point::new(3i32, 4);
+        assert_eq!(p.step(Direction::Up), Point::new(3, 3));
+        assert_eq!(p.step_by(Direction::Left, 5), Point::new(-2, 4));
+        assert_eq!(p.step(Direction8::DownRight), Point::new(4, 5));
+        assert_eq!(p.direction_to(Point::new(3, 5)), Some(Direction::Down));
+        assert_eq!(p.direction_to(Point::new(4, 5)), None);
+        assert_eq!(p.rotate_cw().rotate_ccw(), p);
+        assert_eq!(Point::new(0, -1).rotate_cw(), Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_unsigned_checked() {
+        let origin = Point::new(0usize, 0);
+        assert_eq!(origin.checked_step(Direction::Up, 1), None);
+        assert_eq!(
+            origin.neighbors4().collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(origin.neighbors8().count(), 3);
+        assert_eq!(origin.checked_sub(Point::new(1, 0)), None);
+        assert_eq!(Point::new(1u8, 9).manhattan_distance(Point::new(4, 2)), 10);
+        assert_eq!(
+            Point3::new(1u64, 2, 3).manhattan_distance(Point3::new(3, 2, 1)),
+            4
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use rayon::prelude::*;
+
+use super::geometry::{Direction, Direction8};
+use super::{InputError, InputResult};
+
+/// A cell of a [`Grid`]; row 0 is the first line of the input.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.row, self.col)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build a grid by calling `cell` for every position, row by row.
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Self
+    where
+        F: FnMut(Pos) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(cell(Pos { row, col }));
+            }
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Build a grid from its rows, or `None` if they are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse one cell per character of `lines` with `cell`, which returns `None` for characters
+    /// that can't appear in day `day`'s grid.
+    pub fn parse<F>(day: u8, lines: &[String], mut cell: F) -> InputResult<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut rows = Vec::with_capacity(lines.len());
+        for (r, line) in lines.iter().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(c, chr)| {
+                    cell(chr).ok_or_else(|| {
+                        InputError::parse(day, r, c, format!("unexpected character `{chr}`"))
+                    })
+                })
+                .collect::<InputResult<Vec<T>>>()?;
+            rows.push(row);
+        }
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(InputError::malformed(day, "the grid is empty"));
+        }
+        Self::from_rows(rows)
+            .ok_or_else(|| InputError::malformed(day, "the grid is not rectangular"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// The position `d_row` rows down and `d_col` columns right of `pos`, if it is in the grid.
+    pub fn offset(&self, pos: Pos, d_row: isize, d_col: isize) -> Option<Pos> {
+        let next = Pos {
+            row: pos.row.checked_add_signed(d_row)?,
+            col: pos.col.checked_add_signed(d_col)?,
+        };
+        self.contains(next).then_some(next)
+    }
+
+    /// The adjacent position in `direction`, if it is in the grid.
+    pub fn step(&self, pos: Pos, direction: impl Into<Direction8>) -> Option<Pos> {
+        let (d_col, d_row) = direction.into().delta();
+        self.offset(pos, d_row, d_col)
+    }
+
+    /// The orthogonal neighbors of `pos` that are in the grid, clockwise from up.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are in the grid, clockwise from up.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    /// The rows, for changing them in parallel.
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]>
+    where
+        T: Send,
+    {
+        self.cells.par_chunks_mut(self.width.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {col} is out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.col(col))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos { row, col }))
+    }
+
+    /// Every cell in the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, that matches `pred`.
+    pub fn position<P>(&self, mut pred: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.cols().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotate a quarter turn clockwise, so the first column becomes the first row reversed.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .cols()
+                .flat_map(|col| col.collect::<Vec<_>>().into_iter().rev())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Rotate a square grid a quarter turn clockwise without copying it, like
+    /// [`rotate_cw`](Self::rotate_cw).
+    pub fn rotate_cw_mut(&mut self) {
+        assert_eq!(self.width, self.height, "Only square grids rotate in place");
+        let n = self.width;
+        let idx = |row: usize, col: usize| row * n + col;
+        // Move each cell and the three it trades places with, one ring at a time.
+        for i in 0..n / 2 {
+            for j in i..n - 1 - i {
+                let top_left = idx(i, j);
+                let top_right = idx(j, n - 1 - i);
+                let bottom_right = idx(n - 1 - i, n - 1 - j);
+                let bottom_left = idx(n - 1 - j, i);
+                self.cells.swap(top_left, bottom_left);
+                self.cells.swap(bottom_left, bottom_right);
+                self.cells.swap(bottom_right, top_right);
+            }
+        }
+    }
+
+    /// Rotate a quarter turn counter-clockwise, so the last column becomes the first row.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|col| self.col(col))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Draw the grid one line per row, with `cell` picking each cell's character.
+    pub fn render<F>(&self, mut cell: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the {}x{} grid", self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the {height}x{width} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::get_input_from_str;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::parse(0, &get_input_from_str(s), Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!((g.width(), g.height()), (2, 3));
+        assert_eq!(g[Pos::new(2, 1)], 'f');
+        assert_eq!(g.get(Pos::new(3, 0)), None);
+        assert_eq!(g.to_string(), "ab\ncd\nef");
+
+        let ragged = Grid::parse(0, &get_input_from_str("ab\nc"), Some);
+        assert!(matches!(ragged, Err(InputError::Malformed { .. })));
+        let unknown = Grid::parse(0, &get_input_from_str("..\n.x"), |c| {
+            (c == '.').then_some(c)
+        });
+        assert!(matches!(
+            unknown,
+            Err(InputError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!(
+            g.rows()
+                .map(|r| r.iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ab", "cd", "ef"]
+        );
+        assert_eq!(
+            g.cols().map(|c| c.collect::<String>()).collect::<Vec<_>>(),
+            ["ace", "bdf"]
+        );
+        assert_eq!(g.position(|&c| c == 'd'), Some(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!(g.transpose().to_string(), "ace\nbdf");
+        assert_eq!(g.rotate_cw().to_string(), "eca\nfdb");
+        assert_eq!(g.rotate_ccw().to_string(), "bdf\nace");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+
+        for text in ["abc\ndef\nghi", "abcd\nefgh\nijkl\nmnop", "a"] {
+            let mut g = grid(text);
+            let rotated = g.rotate_cw();
+            g.rotate_cw_mut();
+            assert_eq!(g, rotated);
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = grid("abc\ndef\nghi");
+        let chars = |ps: Vec<Pos>| ps.into_iter().map(|p| g[p]).collect::<String>();
+        assert_eq!(chars(g.neighbors4(Pos::new(1, 1)).collect()), "bfhd");
+        assert_eq!(chars(g.neighbors8(Pos::new(1, 1)).collect()), "bcfihgda");
+        assert_eq!(chars(g.neighbors4(Pos::new(0, 0)).collect()), "bd");
+        assert_eq!(chars(g.neighbors8(Pos::new(2, 2)).collect()), "fhe");
+    }
+}
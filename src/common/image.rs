@@ -0,0 +1,288 @@
+//! Rasterize grids and save them as BMP, PPM or SVG pictures.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use super::grid::{Grid, Pos};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const GRAY: Rgb = Rgb::new(128, 128, 128);
+    pub const DARK_GRAY: Rgb = Rgb::new(64, 64, 64);
+    pub const RED: Rgb = Rgb::new(220, 50, 47);
+    pub const GREEN: Rgb = Rgb::new(133, 153, 0);
+    pub const BLUE: Rgb = Rgb::new(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb::new(250, 200, 40);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse an `rrggbb` hex code, with or without a leading `#`.
+    pub fn from_hex(code: &str) -> Option<Self> {
+        let code = code.strip_prefix('#').unwrap_or(code);
+        match hex::decode(code).ok()?.as_slice() {
+            &[r, g, b] => Some(Self::new(r, g, b)),
+            _ => None,
+        }
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{}", hex::encode([self.r, self.g, self.b]))
+    }
+}
+
+/// Colors for the kinds of cell in a grid, with a default for any kind not listed.
+#[derive(Clone, Debug)]
+pub struct Palette<T> {
+    colors: Vec<(T, Rgb)>,
+    default: Rgb,
+}
+
+impl<T: PartialEq> Palette<T> {
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: Vec::new(),
+            default,
+        }
+    }
+
+    /// Draw cells equal to `value` in `color`.
+    pub fn with(mut self, value: T, color: Rgb) -> Self {
+        self.colors.push((value, color));
+        self
+    }
+
+    pub fn color(&self, value: &T) -> Rgb {
+        self.colors
+            .iter()
+            .find(|(v, _)| v == value)
+            .map_or(self.default, |&(_, color)| color)
+    }
+}
+
+/// The picture formats [`Image::save`] can write.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageFormat {
+    Bmp,
+    Ppm,
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format a file name's extension asks for.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "bmp" => Some(ImageFormat::Bmp),
+            "ppm" => Some(ImageFormat::Ppm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A picture with one pixel per grid cell, drawn `scale` screen pixels wide.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+    scale: u32,
+}
+
+impl Image {
+    pub fn new(pixels: Grid<Rgb>) -> Self {
+        Self { pixels, scale: 1 }
+    }
+
+    /// A picture of `grid`, with `color` picking each cell's color.
+    pub fn from_grid<T, F>(grid: &Grid<T>, color: F) -> Self
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        Self::new(grid.map(color))
+    }
+
+    /// A picture of `grid`, colored by `palette`.
+    pub fn with_palette<T: PartialEq>(grid: &Grid<T>, palette: &Palette<T>) -> Self {
+        Self::from_grid(grid, |cell| palette.color(cell))
+    }
+
+    /// Draw every cell as a `scale` by `scale` square.
+    pub fn scaled(mut self, scale: u32) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Recolor `cells`.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Pos>, color: Rgb) -> Self {
+        for pos in cells {
+            if let Some(pixel) = self.pixels.get_mut(pos) {
+                *pixel = color;
+            }
+        }
+        self
+    }
+
+    /// The size of the picture in screen pixels.
+    pub fn size(&self) -> (u32, u32) {
+        (
+            self.pixels.width() as u32 * self.scale,
+            self.pixels.height() as u32 * self.scale,
+        )
+    }
+
+    /// The color of screen pixel `(x, y)`.
+    fn pixel(&self, x: u32, y: u32) -> Rgb {
+        self.pixels[Pos::new((y / self.scale) as usize, (x / self.scale) as usize)]
+    }
+
+    /// Save to `path`, in the format its extension asks for.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "cannot tell the image format of {} (use .bmp, .ppm or .svg)",
+                    path.display()
+                ),
+            )
+        })?;
+        let mut out = BufWriter::new(File::create(path)?);
+        match format {
+            ImageFormat::Bmp => self.write_bmp(&mut out)?,
+            ImageFormat::Ppm => self.write_ppm(&mut out)?,
+            ImageFormat::Svg => self.write_svg(&mut out)?,
+        }
+        out.flush()
+    }
+
+    pub fn write_bmp<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let (width, height) = self.size();
+        let mut img = bmp::Image::new(width, height);
+        for (x, y) in img.coordinates() {
+            let color = self.pixel(x, y);
+            img.set_pixel(x, y, bmp::Pixel::new(color.r, color.g, color.b));
+        }
+        img.to_writer(out)
+    }
+
+    /// Write a binary (`P6`) PPM.
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let (width, height) = self.size();
+        write!(out, "P6\n{width} {height}\n255\n")?;
+        for y in 0..height {
+            for x in 0..width {
+                let color = self.pixel(x, y);
+                out.write_all(&[color.r, color.g, color.b])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write an SVG with one rectangle per run of same-colored cells in a row, so it stays sharp
+    /// at any size.
+    pub fn write_svg<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let (width, height) = self.size();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            self.pixels.width(),
+            self.pixels.height()
+        )?;
+        for (row, cells) in self.pixels.rows().enumerate() {
+            let mut start = 0;
+            for col in 1..=cells.len() {
+                if col < cells.len() && cells[col] == cells[start] {
+                    continue;
+                }
+                writeln!(
+                    out,
+                    r#"<rect x="{start}" y="{row}" width="{}" height="1" fill="{}"/>"#,
+                    col - start,
+                    cells[start].to_hex()
+                )?;
+                start = col;
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(image: &Image) -> String {
+        let (width, height) = image.size();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| match image.pixel(x, y) {
+                        Rgb::BLACK => '.',
+                        Rgb::WHITE => '#',
+                        Rgb::RED => 'o',
+                        _ => '?',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(Rgb::from_hex("#ff8000"), Some(Rgb::new(255, 128, 0)));
+        assert_eq!(Rgb::from_hex("70c710"), Some(Rgb::new(0x70, 0xc7, 0x10)));
+        assert_eq!(Rgb::from_hex("#fff"), None);
+        assert_eq!(Rgb::new(1, 2, 255).to_hex(), "#0102ff");
+
+        let palette = Palette::new(Rgb::BLACK).with('#', Rgb::WHITE);
+        assert_eq!(palette.color(&'#'), Rgb::WHITE);
+        assert_eq!(palette.color(&'.'), Rgb::BLACK);
+    }
+
+    #[test]
+    fn test_scaled() {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['.', 'o']]).unwrap();
+        let palette = Palette::new(Rgb::BLACK)
+            .with('#', Rgb::WHITE)
+            .with('o', Rgb::RED);
+        let image = Image::with_palette(&grid, &palette).scaled(2);
+        assert_eq!(render(&image), "##..\n##..\n..oo\n..oo");
+    }
+
+    #[test]
+    fn test_formats() {
+        let grid = Grid::from_rows(vec![vec!['#', '#', '.']]).unwrap();
+        let palette = Palette::new(Rgb::BLACK).with('#', Rgb::WHITE);
+        let image = Image::with_palette(&grid, &palette);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert_eq!(&ppm[..11], b"P6\n3 1\n255\n");
+        assert_eq!(&ppm[11..], [255, 255, 255, 255, 255, 255, 0, 0, 0]);
+
+        let mut svg = Vec::new();
+        image.clone().scaled(10).write_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(r#"width="30" height="10" viewBox="0 0 3 1""#));
+        assert_eq!(svg.matches("<rect").count(), 2);
+
+        let mut bmp = Vec::new();
+        image.write_bmp(&mut bmp).unwrap();
+        assert_eq!(&bmp[..2], b"BM");
+        assert_eq!(
+            ImageFormat::from_path(Path::new("out/loop.SVG")),
+            Some(ImageFormat::Svg)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::{get_input, get_input_from_stdin, InputResult};
+
+/// Environment variable that overrides the default input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Directory the inputs are read from when neither a flag nor the environment names one.
+pub const DEFAULT_INPUT_DIR: &str = "src";
+
+/// Which of a day's inputs to run against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSet {
+    /// The example from the puzzle description, `<root>/dayN/example.txt`.
+    Example,
+    /// The personal puzzle input, `<root>/dayN/input.txt`.
+    Real,
+    /// Any other file, used as given.
+    File(PathBuf),
+    /// Lines piped into the process.
+    Stdin,
+}
+
+impl FromStr for InputSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "" => return Err("input set cannot be empty".to_string()),
+            "example" => InputSet::Example,
+            "real" => InputSet::Real,
+            "-" | "stdin" => InputSet::Stdin,
+            path => InputSet::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for InputSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSet::Example => write!(f, "example"),
+            InputSet::Real => write!(f, "real"),
+            InputSet::File(path) => write!(f, "{}", path.display()),
+            InputSet::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// Finds and loads a day's input under a root directory laid out as `<root>/dayN/{example,input}.txt`.
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    root: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Use `root` if given, then `$AOC_INPUT_DIR`, then [`DEFAULT_INPUT_DIR`].
+    pub fn from_env(root: Option<PathBuf>) -> Self {
+        let root = root
+            .or_else(|| std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+        Self::new(root)
+    }
+
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+
+    /// The file `set` refers to for `day`, or `None` for stdin.
+    pub fn path(&self, day: u8, set: &InputSet) -> Option<PathBuf> {
+        let day_dir = self.root.join(format!("day{day}"));
+        match set {
+            InputSet::Example => Some(day_dir.join("example.txt")),
+            InputSet::Real => Some(day_dir.join("input.txt")),
+            InputSet::File(path) => Some(path.clone()),
+            InputSet::Stdin => None,
+        }
+    }
+
+    pub fn load(&self, day: u8, set: &InputSet) -> InputResult<Vec<String>> {
+        match self.path(day, set) {
+            Some(path) => get_input(&path),
+            None => get_input_from_stdin(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_paths() {
+        let resolver = InputResolver::new("inputs");
+        assert_eq!(
+            resolver.path(7, &InputSet::Example),
+            Some(PathBuf::from("inputs/day7/example.txt"))
+        );
+        assert_eq!(
+            resolver.path(7, &InputSet::Real),
+            Some(PathBuf::from("inputs/day7/input.txt"))
+        );
+        assert_eq!(
+            resolver.path(7, &"other/day7.txt".parse().unwrap()),
+            Some(PathBuf::from("other/day7.txt"))
+        );
+        assert_eq!(resolver.path(7, &"-".parse().unwrap()), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_from_non_utf8_root() {
+        use std::ffi::OsStr;
+        use std::fs;
+        use std::os::unix::ffi::OsStrExt;
+
+        // 0xff never appears in UTF-8.
+        let mut name = format!("aoc-inputs-{}-", std::process::id()).into_bytes();
+        name.push(0xff);
+        let root = std::env::temp_dir().join(OsStr::from_bytes(&name));
+        fs::create_dir_all(root.join("day3")).unwrap();
+        fs::write(root.join("day3/example.txt"), "a\nb\n").unwrap();
+        let lines = InputResolver::new(&root).load(3, &InputSet::Example);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(lines.unwrap(), ["a", "b"]);
+    }
+}
@@ -0,0 +1,170 @@
+//! A `log` backend that prints to stderr, with a separate level for each day.
+//!
+//! Solutions log with the usual `log` macros. Their target is their module path, like
+//! `aoc::day20::part1`, which is how messages are matched to a day.
+
+use std::fmt;
+use std::str::FromStr;
+
+use colored::Colorize;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Which messages get printed: a default level, and levels for days that differ from it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LogFilter {
+    default: LevelFilter,
+    days: Vec<(u8, LevelFilter)>,
+}
+
+impl LogFilter {
+    pub fn new(default: LevelFilter) -> Self {
+        Self {
+            default,
+            days: Vec::new(),
+        }
+    }
+
+    /// The default level for a `-v` count: warnings only, then info, debug and trace.
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        Self::new(match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        })
+    }
+
+    /// Apply comma-separated settings on top of these, each either a level for every day (`debug`)
+    /// or a level for one day (`day20=trace` or `20=trace`).
+    pub fn apply(mut self, settings: &str) -> Result<Self, String> {
+        for setting in settings.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match setting.split_once('=') {
+                Some((day, level)) => {
+                    let day = day.trim();
+                    let number = day
+                        .strip_prefix("day")
+                        .unwrap_or(day)
+                        .parse()
+                        .map_err(|_| format!("`{day}` is not a day"))?;
+                    self.days.push((number, parse_level(level.trim())?));
+                }
+                None => self.default = parse_level(setting)?,
+            }
+        }
+        Ok(self)
+    }
+
+    /// The level messages from `target` are printed up to.
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        day_of(target)
+            .and_then(|day| {
+                self.days
+                    .iter()
+                    .rev()
+                    .find(|&&(d, _)| d == day)
+                    .map(|&(_, level)| level)
+            })
+            .unwrap_or(self.default)
+    }
+
+    /// The most verbose level any target is printed up to.
+    pub fn max_level(&self) -> LevelFilter {
+        self.days
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+/// The day a log target belongs to, from its `dayN` module.
+fn day_of(target: &str) -> Option<u8> {
+    target
+        .split("::")
+        .find_map(|part| part.strip_prefix("day")?.parse().ok())
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    level.parse().map_err(|_| {
+        format!("unknown log level `{level}` (expected off, error, warn, info, debug or trace)")
+    })
+}
+
+/// Parses settings as [`LogFilter::apply`] does, on top of printing only warnings and errors.
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LogFilter::new(LevelFilter::Warn).apply(s)
+    }
+}
+
+impl fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.default.as_str().to_lowercase())?;
+        for (day, level) in self.days.iter() {
+            write!(f, ",day{day}={}", level.as_str().to_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+struct Logger {
+    filter: LogFilter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            Level::Error => "ERROR".red(),
+            Level::Warn => "WARN ".yellow(),
+            Level::Info => "INFO ".green(),
+            Level::Debug => "DEBUG".blue(),
+            Level::Trace => "TRACE".dimmed(),
+        };
+        let target = record.target();
+        let target = target.strip_prefix("aoc::").unwrap_or(target);
+        eprintln!("{level} {} {}", target.dimmed(), record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+/// Send log messages to stderr, filtered by `filter`. Only the first call has any effect.
+pub fn init(filter: LogFilter) {
+    let max_level = filter.max_level();
+    if log::set_boxed_logger(Box::new(Logger { filter })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = "info, day20=trace, 5=off".parse::<LogFilter>().unwrap();
+        assert_eq!(filter.level_for("aoc::day20::part1"), LevelFilter::Trace);
+        assert_eq!(filter.level_for("aoc::day5::part2"), LevelFilter::Off);
+        assert_eq!(filter.level_for("aoc::day2::part2"), LevelFilter::Info);
+        assert_eq!(filter.level_for("aoc::common::input"), LevelFilter::Info);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+        assert_eq!(filter.to_string(), "info,day20=trace,day5=off");
+
+        assert!("day20=loud".parse::<LogFilter>().is_err());
+        assert!("dayX=debug".parse::<LogFilter>().is_err());
+
+        let filter = LogFilter::from_verbosity(2).apply("day12=trace").unwrap();
+        assert_eq!(filter.level_for("aoc::day12::part2"), LevelFilter::Trace);
+        assert_eq!(filter.level_for("aoc::day1"), LevelFilter::Debug);
+        let filter = filter.apply("12=info,day12=warn").unwrap();
+        assert_eq!(filter.level_for("aoc::day12::part2"), LevelFilter::Warn);
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::path::Path;
+use std::str::FromStr;
+
+mod answers;
+mod bench;
+mod error;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+mod input;
+pub mod logging;
+pub mod parse;
+pub mod progress;
+pub mod report;
+pub mod search;
+mod solution;
+pub mod visualize;
+
+pub use answers::{AnswerStore, Verdict};
+pub use bench::{sample, Baseline, PartBench, Sample};
+pub use error::{InputError, InputResult};
+pub use input::{InputResolver, InputSet};
+pub use solution::{solve, Answer, Example, Solution};
+
+// The output is wrapped in a Result to allow matching on errors
+// Returns an Iterator to the Reader of the lines of the file.
+pub fn read_lines<P>(filename: P) -> std::io::Result<std::io::Lines<std::io::BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    Ok(std::io::BufReader::new(file).lines())
+}
+
+/// Read every line of the input file at `path`.
+pub fn get_input(path: impl AsRef<Path>) -> InputResult<Vec<String>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|source| InputError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    get_input_from_reader(BufReader::new(file), path)
+}
+
+/// Read every line piped into the process.
+pub fn get_input_from_stdin() -> InputResult<Vec<String>> {
+    get_input_from_reader(std::io::stdin().lock(), Path::new("<stdin>"))
+}
+
+/// Read every line from `reader`, naming it `source` in errors.
+pub fn get_input_from_reader<R: BufRead>(reader: R, source: &Path) -> InputResult<Vec<String>> {
+    reader
+        .lines()
+        .collect::<std::io::Result<Vec<String>>>()
+        .map_err(|err| InputError::Io {
+            path: source.to_path_buf(),
+            source: err,
+        })
+}
+
+/// Split an in-memory input into lines, the same way the file loaders do.
+pub fn get_input_from_str(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+/// Solve the given part of puzzle `S` for an input held in memory.
+pub fn solve_str<S: Solution>(part: u8, input: &str) -> InputResult<Answer> {
+    solve::<S>(part, get_input_from_str(input))
+}
+
+/// Parse `token`, which must be a slice of `line`, reporting where it sits in the input on failure.
+pub fn parse_token<T>(day: u8, line_idx: usize, line: &str, token: &str) -> InputResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse().map_err(|err| {
+        InputError::parse(
+            day,
+            line_idx,
+            column_of(line, token),
+            format!("cannot parse `{token}`: {err}"),
+        )
+    })
+}
+
+/// The 0-based column at which `token`, a slice of `line`, starts.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    line.get(..offset)
+        .map_or(0, |prefix| prefix.chars().count())
+}
+
+/// Check every example of puzzle `S` that has an expected answer for `part`.
+#[cfg(test)]
+pub fn check_examples<S: Solution>(part: u8) {
+    let mut n_checked = 0;
+    for (i, example) in S::EXAMPLES.iter().enumerate() {
+        let expected = match part {
+            1 => example.part1,
+            2 => example.part2,
+            _ => panic!("Invalid part {part}"),
+        };
+        if let Some(expected) = expected {
+            let answer = solve_str::<S>(part, example.input)
+                .unwrap_or_else(|err| panic!("example {} does not parse: {err}", i + 1));
+            assert_eq!(
+                answer.to_string(),
+                expected,
+                "part {part}, example {}",
+                i + 1
+            );
+            n_checked += 1;
+        }
+    }
+    assert_ne!(n_checked, 0, "no examples for part {part}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token() {
+        let line = "10 -3 x7";
+        let tokens = line.split_ascii_whitespace().collect::<Vec<_>>();
+        assert_eq!(parse_token::<i64>(9, 0, line, tokens[1]).unwrap(), -3);
+        match parse_token::<i64>(9, 4, line, tokens[2]) {
+            Err(InputError::Parse {
+                day: 9,
+                line: 5,
+                column: 7,
+                ..
+            }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get_input_from_str() {
+        assert_eq!(
+            get_input_from_str("a b\r\n\nc\n"),
+            vec!["a b".to_string(), "".to_string(), "c".to_string()]
+        );
+        assert!(get_input_from_str("").is_empty());
+    }
+
+    #[test]
+    fn test_get_input_from_reader() {
+        let reader = std::io::Cursor::new("a b\r\n\nc\n");
+        assert_eq!(
+            get_input_from_reader(reader, Path::new("test")).unwrap(),
+            get_input_from_str("a b\r\n\nc\n")
+        );
+    }
+
+    #[test]
+    fn test_get_input_missing_file() {
+        assert!(matches!(
+            get_input("does/not/exist.txt"),
+            Err(InputError::Io { .. })
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+use super::{column_of, parse_token, InputError, InputResult};
+
+/// One line of a day's input, remembered so that errors can point at where they happened.
+///
+/// Every `text` argument must be a slice of the line itself.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// 0-based index of the line in the input.
+    pub idx: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(day: u8, idx: usize, text: &'a str) -> Self {
+        Self { day, idx, text }
+    }
+
+    /// An error pointing at the start of `at`.
+    pub fn error(&self, at: &str, message: impl Into<String>) -> InputError {
+        InputError::parse(self.day, self.idx, column_of(self.text, at), message)
+    }
+
+    /// Parse a single token.
+    pub fn token<T>(&self, token: &str) -> InputResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_token(self.day, self.idx, self.text, token)
+    }
+
+    /// Parse whitespace-separated values, like `79 14 55 13`.
+    pub fn ints<T>(&self, text: &str) -> InputResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.split_ascii_whitespace()
+            .map(|token| self.token(token))
+            .collect()
+    }
+
+    /// Parse values separated by `sep`, ignoring the whitespace around them, like `a, b, c`.
+    pub fn list<T>(&self, text: &str, sep: char) -> InputResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.split(sep)
+            .map(|token| self.token(token.trim()))
+            .collect()
+    }
+
+    /// Split `text` around the first `sep`, which must be there.
+    pub fn split_once(&self, text: &'a str, sep: &str) -> InputResult<(&'a str, &'a str)> {
+        text.split_once(sep)
+            .ok_or_else(|| self.error(text, format!("expected `{sep}`")))
+    }
+
+    /// Split a `key: values` line into its key and whitespace-separated values.
+    pub fn key_values<T>(&self) -> InputResult<(&'a str, Vec<T>)>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (key, values) = self.split_once(self.text, ":")?;
+        Ok((key.trim(), self.ints(values)?))
+    }
+
+    /// Match the whole line against `regex`.
+    pub fn captures(&self, regex: &Regex) -> InputResult<Captures<'a>> {
+        regex
+            .captures(self.text)
+            .ok_or_else(|| self.error(self.text, format!("does not match `{regex}`")))
+    }
+}
+
+/// Parse every line of `lines` with `f`.
+pub fn each_line<T, F>(day: u8, lines: &[String], mut f: F) -> InputResult<Vec<T>>
+where
+    F: FnMut(Line) -> InputResult<T>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, text)| f(Line::new(day, idx, text)))
+        .collect()
+}
+
+/// Split `lines` into blocks separated by blank lines, each with the index of its first line.
+pub fn blocks(lines: &[String]) -> impl Iterator<Item = (usize, &[String])> {
+    let mut start = 0;
+    lines
+        .split(|line| line.is_empty())
+        .filter_map(move |block| {
+            let first = start;
+            start += block.len() + 1;
+            (!block.is_empty()).then_some((first, block))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::get_input_from_str;
+
+    #[test]
+    fn test_line_helpers() {
+        let line = Line::new(6, 0, "Time:      7  15   30");
+        assert_eq!(line.key_values::<u32>().unwrap(), ("Time", vec![7, 15, 30]));
+
+        let line = Line::new(20, 3, "%a -> b, c");
+        let (_, outputs) = line.split_once(line.text, " -> ").unwrap();
+        assert_eq!(line.list::<String>(outputs, ',').unwrap(), ["b", "c"]);
+        assert!(line.split_once(line.text, ": ").is_err());
+
+        let line = Line::new(4, 1, "Card 2: 13 x2 | 61");
+        match line.key_values::<u32>() {
+            Err(InputError::Parse {
+                line: 2,
+                column: 12,
+                ..
+            }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_blocks() {
+        let lines = get_input_from_str("a\nb\n\n\nc\n\nd\ne");
+        let blocks = blocks(&lines)
+            .map(|(i, b)| (i, b.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, [(0, 2), (4, 1), (6, 2)]);
+    }
+}
@@ -0,0 +1,201 @@
+//! Progress reporting for long-running solutions.
+//!
+//! Solutions create a [`Progress`] and call [`Progress::inc`] as they go. How that is shown is up
+//! to the runner, which picks a [`ProgressMode`] with [`set_mode`] before solving.
+
+use std::fmt;
+use std::panic::Location;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use log::info;
+
+/// How progress is shown.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProgressMode {
+    /// A bar on stderr.
+    Bar,
+    /// An info message every tenth of the way.
+    Log,
+    /// Nothing at all.
+    Off,
+}
+
+impl FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bar" => Ok(ProgressMode::Bar),
+            "log" => Ok(ProgressMode::Log),
+            "off" => Ok(ProgressMode::Off),
+            _ => Err(format!(
+                "unknown progress mode `{s}` (expected bar, log or off)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ProgressMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressMode::Bar => write!(f, "bar"),
+            ProgressMode::Log => write!(f, "log"),
+            ProgressMode::Off => write!(f, "off"),
+        }
+    }
+}
+
+/// The mode new [`Progress`]es use. It is shared by every thread, since `run --all` solves days on
+/// a thread pool.
+static MODE: AtomicU8 = AtomicU8::new(ProgressMode::Off as u8);
+
+pub fn set_mode(mode: ProgressMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn mode() -> ProgressMode {
+    match MODE.load(Ordering::Relaxed) {
+        0 => ProgressMode::Bar,
+        1 => ProgressMode::Log,
+        _ => ProgressMode::Off,
+    }
+}
+
+/// The log target for code in the source file `file`, like `aoc::day5::part2` for
+/// `src/day5/part2.rs`, so per-day log levels apply to progress messages too.
+fn log_target(file: &str) -> String {
+    let module = file
+        .trim_start_matches("src/")
+        .trim_end_matches(".rs")
+        .trim_end_matches("/mod")
+        .replace(['/', '\\'], "::");
+    format!("aoc::{module}")
+}
+
+/// Counts towards a known total, logging each tenth reached.
+struct Counter {
+    target: String,
+    label: String,
+    total: u64,
+    done: AtomicU64,
+    tenths: AtomicU64,
+}
+
+impl Counter {
+    fn inc(&self, n: u64) {
+        let done = self.done.fetch_add(n, Ordering::Relaxed) + n;
+        let tenths = (done.min(self.total) * 10) / self.total.max(1);
+        if self.tenths.fetch_max(tenths, Ordering::Relaxed) < tenths {
+            info!(
+                target: &self.target,
+                "{}: {done}/{} ({}%)",
+                self.label,
+                self.total,
+                tenths * 10
+            );
+        }
+    }
+}
+
+/// How progress is shown: a bar, occasional log lines, or not at all.
+enum Indicator {
+    Bar(ProgressBar),
+    Log(Counter),
+    Off,
+}
+
+/// Progress through `total` steps of work, shown however the runner asked for.
+pub struct Progress {
+    indicator: Indicator,
+}
+
+impl Progress {
+    #[track_caller]
+    pub fn new(label: impl Into<String>, total: u64) -> Self {
+        let label = label.into();
+        let indicator = match mode() {
+            ProgressMode::Bar => {
+                let style = ProgressStyle::with_template(
+                    "{msg} [{elapsed_precise}] {wide_bar} {human_pos}/{human_len} ({eta})",
+                )
+                .expect("Progress bar template is valid");
+                Indicator::Bar(
+                    ProgressBar::new(total)
+                        .with_style(style)
+                        .with_message(label),
+                )
+            }
+            ProgressMode::Log => Indicator::Log(Counter {
+                target: log_target(Location::caller().file()),
+                label,
+                total,
+                done: AtomicU64::new(0),
+                tenths: AtomicU64::new(0),
+            }),
+            ProgressMode::Off => Indicator::Off,
+        };
+        Self { indicator }
+    }
+
+    /// Record `n` more steps done.
+    pub fn inc(&self, n: u64) {
+        match &self.indicator {
+            Indicator::Bar(bar) => bar.inc(n),
+            Indicator::Log(counter) => counter.inc(n),
+            Indicator::Off => {}
+        }
+    }
+
+    pub fn finish(&self) {
+        match &self.indicator {
+            Indicator::Bar(bar) => bar.finish_and_clear(),
+            Indicator::Log(counter) => info!(
+                target: &counter.target,
+                "{}: finished {} steps",
+                counter.label,
+                counter.done.load(Ordering::Relaxed)
+            ),
+            Indicator::Off => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modes() {
+        for mode in [ProgressMode::Bar, ProgressMode::Log, ProgressMode::Off] {
+            assert_eq!(mode.to_string().parse(), Ok(mode));
+        }
+        assert!("quiet".parse::<ProgressMode>().is_err());
+        // Nothing has set the mode in tests, so progress is off.
+        assert!(matches!(
+            Progress::new("test", 10).indicator,
+            Indicator::Off
+        ));
+    }
+
+    #[test]
+    fn test_counter() {
+        assert_eq!(log_target("src/day5/part2.rs"), "aoc::day5::part2");
+        assert_eq!(log_target("src/day14/mod.rs"), "aoc::day14");
+
+        let counter = Counter {
+            target: "aoc::test".to_string(),
+            label: "test".to_string(),
+            total: 20,
+            done: AtomicU64::new(0),
+            tenths: AtomicU64::new(0),
+        };
+        counter.inc(1);
+        assert_eq!(counter.tenths.load(Ordering::Relaxed), 0);
+        counter.inc(5);
+        assert_eq!(counter.tenths.load(Ordering::Relaxed), 3);
+        counter.inc(100);
+        assert_eq!(counter.tenths.load(Ordering::Relaxed), 10);
+    }
+}
@@ -0,0 +1,225 @@
+//! Machine-readable records of solved parts, written as JSON or CSV.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    /// Lines and tables for people to read.
+    Text,
+    /// A JSON array with one object per part.
+    Json,
+    /// CSV with a header row and one row per part.
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "unknown output format `{s}` (expected text, json or csv)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// How solving a part went, as far as scripts need to know.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The answer matches the recorded one.
+    Correct,
+    /// The answer differs from the recorded one.
+    Wrong,
+    /// There is no recorded answer to check against.
+    Unverified,
+    /// The input could not be read or parsed.
+    Error,
+    Panicked,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Correct => write!(f, "correct"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::Unverified => write!(f, "unverified"),
+            Status::Error => write!(f, "error"),
+            Status::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+/// The result of running one part of one day.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ResultRecord {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// The answer, if the part was solved.
+    pub answer: Option<String>,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_ms")]
+    pub duration: Duration,
+    pub status: Status,
+    /// The expected answer for wrong answers, or what went wrong for failures.
+    pub detail: Option<String>,
+}
+
+fn millis(duration: &Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+const FIELDS: [&str; 7] = [
+    "day",
+    "part",
+    "input",
+    "answer",
+    "duration_ms",
+    "status",
+    "detail",
+];
+
+fn serialize_ms<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(millis(duration))
+}
+
+/// `s` as a CSV field, quoted only if it has to be.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Write `records` as a JSON array, one record per line.
+pub fn write_json<W: Write>(out: &mut W, records: &[ResultRecord]) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (i, r) in records.iter().enumerate() {
+        let comma = if i + 1 < records.len() { "," } else { "" };
+        writeln!(out, "  {}{comma}", serde_json::to_string(r)?)?;
+    }
+    writeln!(out, "]")
+}
+
+pub fn write_csv<W: Write>(out: &mut W, records: &[ResultRecord]) -> io::Result<()> {
+    writeln!(out, "{}", FIELDS.join(","))?;
+    for r in records {
+        let values = [
+            r.day.to_string(),
+            r.part.to_string(),
+            csv_field(&r.input),
+            csv_field(r.answer.as_deref().unwrap_or_default()),
+            format!("{:.3}", millis(&r.duration)),
+            r.status.to_string(),
+            csv_field(r.detail.as_deref().unwrap_or_default()),
+        ];
+        writeln!(out, "{}", values.join(","))?;
+    }
+    Ok(())
+}
+
+/// Write `records` in `format`, which must be JSON or CSV.
+pub fn write_records<W: Write>(
+    out: &mut W,
+    format: OutputFormat,
+    records: &[ResultRecord],
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Json => write_json(out, records),
+        OutputFormat::Csv => write_csv(out, records),
+        OutputFormat::Text => panic!("Text output is not made of records"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<ResultRecord> {
+        vec![
+            ResultRecord {
+                day: 1,
+                part: 2,
+                input: "example".to_string(),
+                answer: Some("281".to_string()),
+                duration: Duration::from_micros(1500),
+                status: Status::Correct,
+                detail: None,
+            },
+            ResultRecord {
+                day: 19,
+                part: 2,
+                input: "my \"odd\", input.txt".to_string(),
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Panicked,
+                detail: Some("not yet\nimplemented".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &records()).unwrap();
+        let json = String::from_utf8(out).unwrap();
+        assert_eq!(
+            json.lines().nth(1),
+            Some(
+                r#"  {"day":1,"part":2,"input":"example","answer":"281","duration_ms":1.5,"status":"correct","detail":null},"#
+            )
+        );
+        assert!(json.contains(r#""input":"my \"odd\", input.txt","answer":null"#));
+        assert!(json.contains(r#""detail":"not yet\nimplemented"}"#));
+        assert!(json.ends_with("}\n]\n"));
+
+        let mut out = Vec::new();
+        write_json(&mut out, &[]).unwrap();
+        assert_eq!(out, b"[\n]\n");
+    }
+
+    #[test]
+    fn test_json_control_characters() {
+        let mut record = records().remove(0);
+        record.detail = Some((0..0x20).map(char::from).collect());
+        let mut out = Vec::new();
+        write_json(&mut out, &[record]).unwrap();
+        let json = String::from_utf8(out).unwrap();
+        assert!(json.contains(
+            r#""detail":"\u0000\u0001\u0002\u0003\u0004\u0005\u0006\u0007\b\t\n\u000b\f\r\u000e\u000f\u0010\u0011\u0012\u0013\u0014\u0015\u0016\u0017\u0018\u0019\u001a\u001b\u001c\u001d\u001e\u001f""#
+        ));
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &records()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,part,input,answer,duration_ms,status,detail\n\
+             1,2,example,281,1.500,correct,\n\
+             19,2,\"my \"\"odd\"\", input.txt\",,0.000,panicked,\"not yet\nimplemented\"\n"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+use priority_queue::PriorityQueue;
+
+/// How much work a search did.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct SearchStats {
+    /// Nodes taken off the frontier and expanded.
+    pub visited: usize,
+    /// Distinct nodes that were ever reached.
+    pub discovered: usize,
+}
+
+/// A path from the start to a goal, including both ends.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    /// The number of moves along the path.
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SearchResult<N, C> {
+    /// The cheapest path to a goal, or `None` if no goal is reachable.
+    pub path: Option<Path<N, C>>,
+    pub stats: SearchStats,
+}
+
+/// Walk `came_from` links back from `goal` to the start.
+fn reconstruct_path<N, C>(came_from: &HashMap<N, N>, goal: N, cost: C) -> Path<N, C>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut nodes = vec![goal];
+    while let Some(prev) = came_from.get(nodes.last().unwrap()) {
+        nodes.push(prev.clone());
+    }
+    nodes.reverse();
+    Path { nodes, cost }
+}
+
+/// Breadth-first search from `start` to the nearest node matching `is_goal`, where every move
+/// costs 1.
+pub fn bfs<N, I, F, G>(start: N, mut neighbors: F, mut is_goal: G) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut stats = SearchStats::default();
+    let mut came_from = HashMap::new();
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        stats.visited += 1;
+        let distance = distances[&current];
+        if is_goal(&current) {
+            stats.discovered = distances.len();
+            return SearchResult {
+                path: Some(reconstruct_path(&came_from, current, distance)),
+                stats,
+            };
+        }
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                came_from.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    stats.discovered = distances.len();
+    SearchResult { path: None, stats }
+}
+
+/// The number of moves from `start` to every node reachable from it.
+pub fn bfs_distances<N, I, F>(start: N, mut neighbors: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current];
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest node matching `is_goal`. `neighbors`
+/// returns each next node with the cost of moving to it.
+pub fn dijkstra<N, C, I, F, G>(start: N, neighbors: F, is_goal: G) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `start` to the cheapest node matching `is_goal`. `heuristic` must never
+/// overestimate the remaining cost, or the path found may not be the cheapest.
+pub fn astar<N, C, I, F, H, G>(
+    start: N,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut stats = SearchStats::default();
+    let mut came_from = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), C::zero())]);
+    let mut open = PriorityQueue::new();
+    open.push(start.clone(), Reverse(heuristic(&start)));
+    while let Some((current, _)) = open.pop() {
+        stats.visited += 1;
+        let cost = costs[&current];
+        if is_goal(&current) {
+            stats.discovered = costs.len();
+            return SearchResult {
+                path: Some(reconstruct_path(&came_from, current, cost)),
+                stats,
+            };
+        }
+        for (next, step_cost) in neighbors(&current) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&old| next_cost < old) {
+                costs.insert(next.clone(), next_cost);
+                came_from.insert(next.clone(), current.clone());
+                let priority = next_cost + heuristic(&next);
+                open.push(next, Reverse(priority));
+            }
+        }
+    }
+    stats.discovered = costs.len();
+    SearchResult { path: None, stats }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 2 + 1.
+    fn weighted(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(0, |n| weighted(n).into_iter().map(|(m, _)| m), |&n| n == 3);
+        let path = result.path.unwrap();
+        assert_eq!(path.nodes, [0, 1, 3]);
+        assert_eq!((path.cost, path.steps()), (2, 2));
+        assert_eq!(result.stats.discovered, 4);
+
+        assert_eq!(bfs(0, |&n| [n + 1], |&n| n > 10).path.unwrap().cost, 11);
+        assert_eq!(bfs(0, |&n| [(n + 1) % 5], |&n| n == 7).path, None);
+        assert_eq!(
+            bfs_distances(0, |n| weighted(n).into_iter().map(|(m, _)| m))[&3],
+            2
+        );
+    }
+
+    #[test]
+    fn test_weighted() {
+        let path = dijkstra(0, weighted, |&n| n == 3).path.unwrap();
+        assert_eq!((path.nodes, path.cost), (vec![0, 2, 3], 3));
+
+        let result = astar(0, weighted, |&n| 3 - n.min(3), |&n| n == 3);
+        assert_eq!(result.path.unwrap().cost, 3);
+        assert!(result.stats.visited <= 4);
+        assert_eq!(dijkstra(1, weighted, |&n| n == 2).path, None);
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use super::InputResult;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Int, i64, i32, i64, isize);
+answer_from!(UInt, u64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// An example input from the puzzle text and the answers it gives for each part.
+/// A part is `None` when the example isn't meant for it.
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// A day's puzzle: parse the input once, then solve either part from the parsed input.
+pub trait Solution {
+    type Input;
+
+    /// The examples from the puzzle text, checked end-to-end by each day's tests.
+    const EXAMPLES: &'static [Example];
+
+    /// Parse the input, rejecting lines the solution can't make sense of.
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Parse `lines` and solve the given part (1 or 2) of puzzle `S`.
+pub fn solve<S: Solution>(part: u8, lines: Vec<String>) -> InputResult<Answer> {
+    let input = S::parse(lines)?;
+    Ok(match part {
+        1 => S::part1(&input),
+        2 => S::part2(&input),
+        _ => panic!("Invalid part {part}"),
+    })
+}
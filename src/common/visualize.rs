@@ -0,0 +1,443 @@
+//! Record frames while a solution runs, then play them back in the terminal.
+//!
+//! Solutions call [`record`] at interesting points. Recording is off unless `aoc visualize` turned
+//! it on, and the closure building the frame only runs while it is on, so the hooks cost nothing in
+//! normal runs.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::io::{self, Stdout};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use tui::backend::CrosstermBackend;
+use tui::layout::{Constraint, Layout};
+use tui::style::{Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Paragraph};
+use tui::Terminal;
+
+pub use tui::style::Color;
+
+use super::grid::{Grid, Pos};
+use super::image::{Image, Palette, Rgb};
+
+/// Recordings longer than this keep every other frame, so long runs still cover the whole
+/// solution without filling memory.
+const MAX_FRAMES: usize = 4096;
+
+/// One snapshot of a solution's state.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub title: String,
+    pub grid: Grid<char>,
+    /// Cells drawn in a color other than the default; later entries win.
+    pub highlights: Vec<(Pos, Color)>,
+    pub counters: Vec<(String, String)>,
+}
+
+impl Frame {
+    pub fn new(title: impl Into<String>, grid: Grid<char>) -> Self {
+        Self {
+            title: title.into(),
+            grid,
+            highlights: Vec::new(),
+            counters: Vec::new(),
+        }
+    }
+
+    /// A frame drawing each cell of `grid` as the character `cell` picks for it.
+    pub fn from_grid<T, F>(title: impl Into<String>, grid: &Grid<T>, cell: F) -> Self
+    where
+        F: FnMut(&T) -> char,
+    {
+        Self::new(title, grid.map(cell))
+    }
+
+    /// Draw `cells` in `color`.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Pos>, color: Color) -> Self {
+        self.highlights
+            .extend(cells.into_iter().map(|pos| (pos, color)));
+        self
+    }
+
+    /// Show `value` under the grid, labelled `label`.
+    pub fn counter(mut self, label: impl Into<String>, value: impl Display) -> Self {
+        self.counters.push((label.into(), value.to_string()));
+        self
+    }
+
+    /// A picture of the frame, with highlighted cells in their color and `palette` coloring the
+    /// rest.
+    pub fn to_image(&self, palette: &Palette<char>) -> Image {
+        let colors = self.colors();
+        Image::new(Grid::from_fn(
+            self.grid.width(),
+            self.grid.height(),
+            |pos| colors[pos].map_or_else(|| palette.color(&self.grid[pos]), rgb),
+        ))
+    }
+
+    fn colors(&self) -> Grid<Option<Color>> {
+        let mut colors = self.grid.map(|_| None);
+        for &(pos, color) in self.highlights.iter() {
+            if let Some(cell) = colors.get_mut(pos) {
+                *cell = Some(color);
+            }
+        }
+        colors
+    }
+
+    fn lines(&self) -> Vec<Spans<'static>> {
+        let colors = self.colors();
+        self.grid
+            .rows()
+            .zip(colors.rows())
+            .map(|(cells, colors)| {
+                Spans(
+                    cells
+                        .iter()
+                        .zip(colors)
+                        .map(|(&chr, color)| match color {
+                            Some(color) => Span::styled(
+                                chr.to_string(),
+                                Style::default().fg(*color).add_modifier(Modifier::BOLD),
+                            ),
+                            None => Span::raw(chr.to_string()),
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// The colors frames are exported with when nothing else is asked for: open cells dark, walls
+/// light, and anything else in between.
+pub fn default_palette() -> Palette<char> {
+    Palette::new(Rgb::DARK_GRAY)
+        .with('.', Rgb::BLACK)
+        .with(' ', Rgb::BLACK)
+        .with('#', Rgb::GRAY)
+}
+
+/// The RGB color a terminal color is exported as.
+fn rgb(color: Color) -> Rgb {
+    match color {
+        Color::Rgb(r, g, b) => Rgb::new(r, g, b),
+        Color::Black => Rgb::BLACK,
+        Color::Red | Color::LightRed => Rgb::RED,
+        Color::Green | Color::LightGreen => Rgb::GREEN,
+        Color::Yellow | Color::LightYellow => Rgb::YELLOW,
+        Color::Blue | Color::LightBlue => Rgb::BLUE,
+        Color::Magenta | Color::LightMagenta => Rgb::new(211, 54, 130),
+        Color::Cyan | Color::LightCyan => Rgb::new(42, 161, 152),
+        Color::Gray => Rgb::GRAY,
+        Color::DarkGray => Rgb::DARK_GRAY,
+        Color::White | Color::Reset | Color::Indexed(_) => Rgb::WHITE,
+    }
+}
+
+/// Frames recorded so far, thinned out once there are too many.
+#[derive(Default)]
+struct Recording {
+    frames: Vec<Frame>,
+    /// Only every `stride`th call to [`record`] is kept.
+    stride: usize,
+    calls: usize,
+    /// The most recent frame, kept so the recording always ends on the final state.
+    last: Option<Frame>,
+}
+
+impl Recording {
+    fn new() -> Self {
+        Self {
+            stride: 1,
+            ..Default::default()
+        }
+    }
+
+    fn push(&mut self, frame: Frame) {
+        let keep = self.calls.is_multiple_of(self.stride);
+        self.calls += 1;
+        if !keep {
+            self.last = Some(frame);
+            return;
+        }
+        self.last = None;
+        self.frames.push(frame);
+        if self.frames.len() >= MAX_FRAMES {
+            let mut i = 0;
+            self.frames.retain(|_| {
+                i += 1;
+                i % 2 == 1
+            });
+            self.stride *= 2;
+        }
+    }
+
+    fn finish(mut self) -> Vec<Frame> {
+        self.frames.extend(self.last);
+        self.frames
+    }
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Start recording frames on this thread, dropping any recorded before.
+pub fn start_recording() {
+    RECORDING.with(|r| *r.borrow_mut() = Some(Recording::new()));
+}
+
+/// Stop recording and return the frames recorded since [`start_recording`].
+pub fn stop_recording() -> Vec<Frame> {
+    RECORDING
+        .with(|r| r.borrow_mut().take())
+        .map_or_else(Vec::new, Recording::finish)
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.with(|r| r.borrow().is_some())
+}
+
+/// Record the frame `frame` builds, if this thread is recording.
+pub fn record<F>(frame: F)
+where
+    F: FnOnce() -> Frame,
+{
+    RECORDING.with(|r| {
+        if let Some(recording) = r.borrow_mut().as_mut() {
+            recording.push(frame());
+        }
+    });
+}
+
+/// Frames per second the player starts at, and its limits.
+const DEFAULT_FPS: u32 = 10;
+const MAX_FPS: u32 = 240;
+
+/// Where the player is and how it is moving.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct PlayerState {
+    frame: usize,
+    n_frames: usize,
+    playing: bool,
+    fps: u32,
+}
+
+impl PlayerState {
+    fn new(n_frames: usize) -> Self {
+        Self {
+            frame: 0,
+            n_frames,
+            playing: false,
+            fps: DEFAULT_FPS,
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    fn last_frame(&self) -> usize {
+        self.n_frames.saturating_sub(1)
+    }
+
+    /// Move on one frame while playing, pausing at the end.
+    fn tick(&mut self) {
+        if self.frame < self.last_frame() {
+            self.frame += 1;
+        }
+        if self.frame == self.last_frame() {
+            self.playing = false;
+        }
+    }
+
+    /// Apply a key press; returns `false` once the player should quit.
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => {
+                if !self.playing && self.frame == self.last_frame() {
+                    self.frame = 0;
+                }
+                self.playing = !self.playing;
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.playing = false;
+                self.frame = (self.frame + 1).min(self.last_frame());
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.playing = false;
+                self.frame = self.frame.saturating_sub(1);
+            }
+            KeyCode::Home => self.frame = 0,
+            KeyCode::End => self.frame = self.last_frame(),
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => {
+                self.fps = (self.fps * 2).min(MAX_FPS)
+            }
+            KeyCode::Char('-') | KeyCode::Down => self.fps = (self.fps / 2).max(1),
+            _ => {}
+        }
+        true
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "frame {}/{}  {} at {} fps  [space] play/pause  [←/→] step  [+/-] speed  [q] quit",
+            self.frame + 1,
+            self.n_frames,
+            if self.playing { "playing" } else { "paused" },
+            self.fps
+        )
+    }
+}
+
+fn draw(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    frame: &Frame,
+    state: &PlayerState,
+) -> io::Result<()> {
+    terminal.draw(|f| {
+        let counters = frame
+            .counters
+            .iter()
+            .map(|(label, value)| format!("{label}: {value}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let chunks = Layout::default()
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(f.size());
+        let grid = Paragraph::new(frame.lines())
+            .block(Block::default().borders(Borders::ALL).title(&*frame.title));
+        f.render_widget(grid, chunks[0]);
+        f.render_widget(Paragraph::new(counters), chunks[1]);
+        f.render_widget(
+            Paragraph::new(state.status()).style(Style::default().fg(Color::DarkGray)),
+            chunks[2],
+        );
+    })?;
+    Ok(())
+}
+
+fn play_loop(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    frames: &[Frame],
+) -> io::Result<()> {
+    let mut state = PlayerState::new(frames.len());
+    let mut next_tick = Instant::now();
+    loop {
+        draw(terminal, &frames[state.frame], &state)?;
+        let timeout = if state.playing {
+            next_tick.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(60)
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !state.handle_key(key.code) {
+                    return Ok(());
+                }
+                next_tick = Instant::now() + state.interval();
+            }
+        } else if state.playing {
+            state.tick();
+            next_tick += state.interval();
+        }
+    }
+}
+
+/// Play `frames` in the terminal until the user quits.
+pub fn play(frames: &[Frame]) -> io::Result<()> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let result = Terminal::new(CrosstermBackend::new(stdout)).and_then(|mut terminal| {
+        terminal.hide_cursor()?;
+        let result = play_loop(&mut terminal, frames);
+        terminal.show_cursor()?;
+        result
+    });
+    // Put the terminal back even if drawing failed.
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blank(title: &str) -> Frame {
+        Frame::new(title, Grid::new(2, 1, '.'))
+    }
+
+    #[test]
+    fn test_recording() {
+        record(|| panic!("frames are only built while recording"));
+
+        start_recording();
+        assert!(is_recording());
+        for i in 0..MAX_FRAMES * 2 + 1 {
+            record(|| blank(&i.to_string()));
+        }
+        let frames = stop_recording();
+        assert!(!is_recording());
+        assert!(frames.len() <= MAX_FRAMES);
+        assert_eq!(frames[0].title, "0");
+        assert_eq!(frames.last().unwrap().title, (MAX_FRAMES * 2).to_string());
+        assert!(stop_recording().is_empty());
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = blank("t")
+            .highlight([Pos::new(0, 1), Pos::new(5, 5)], Color::Red)
+            .counter("beams", 3);
+        assert_eq!(frame.colors().row(0), [None, Some(Color::Red)]);
+        assert_eq!(frame.counters, [("beams".to_string(), "3".to_string())]);
+
+        let mut ppm = Vec::new();
+        frame
+            .to_image(&default_palette())
+            .write_ppm(&mut ppm)
+            .unwrap();
+        assert_eq!(&ppm[ppm.len() - 6..], [0, 0, 0, 220, 50, 47]);
+    }
+
+    #[test]
+    fn test_player_keys() {
+        let mut state = PlayerState::new(3);
+        assert!(state.handle_key(KeyCode::Right));
+        assert!(state.handle_key(KeyCode::Right));
+        assert!(state.handle_key(KeyCode::Right));
+        assert_eq!(state.frame, 2);
+        state.handle_key(KeyCode::Left);
+        assert_eq!(state.frame, 1);
+
+        state.handle_key(KeyCode::Char(' '));
+        assert!(state.playing);
+        state.tick();
+        assert_eq!((state.frame, state.playing), (2, false));
+        // Playing again from the end starts over.
+        state.handle_key(KeyCode::Char(' '));
+        assert_eq!((state.frame, state.playing), (0, true));
+
+        state.handle_key(KeyCode::Char('-'));
+        assert_eq!(state.fps, DEFAULT_FPS / 2);
+        assert!(!state.handle_key(KeyCode::Char('q')));
+    }
+}
@@ -1,95 +1,157 @@
 // https://adventofcode.com/2023/day/1
 
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-const DIGITS: [(&str, u32); 19] = [
-    ("0", 0),
-    ("1", 1),
-    ("2", 2),
-    ("3", 3),
-    ("4", 4),
-    ("5", 5),
-    ("6", 6),
-    ("7", 7),
-    ("8", 8),
-    ("9", 9),
-    ("one", 1),
-    ("two", 2),
-    ("three", 3),
-    ("four", 4),
-    ("five", 5),
-    ("six", 6),
-    ("seven", 7),
-    ("eight", 8),
-    ("nine", 9),
-];
-
-struct DigitMatch {
-    digit: u32,
-    index: usize,
+use std::fmt;
+
+use log::warn;
+use once_cell::sync::Lazy;
+
+use crate::common::{Answer, Example, InputResult, Solution};
+pub use scanner::Mode;
+pub use vocabulary::{Vocabulary, BUILTINS};
+
+mod scanner;
+mod vocabulary;
+
+static ENGLISH: Lazy<Vocabulary> = Lazy::new(Vocabulary::english);
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("example.txt"),
+            part1: Some("142"),
+            part2: None,
+        },
+        Example {
+            input: include_str!("example2.txt"),
+            part1: None,
+            part2: Some("281"),
+        },
+    ];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        calibrate(input, Mode::Numerals)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        calibrate(input, Mode::Words)
+    }
 }
 
-pub fn run() {
-    // Create a path to the desired file
-    let path = Path::new("src/day1/input2.txt");
-    let mut sum = 0;
-    match read_lines(path) {
-        Ok(lines) => {
-            for line in lines {
-                match line {
-                    Ok(line) => {
-                        sum += secret_number(&line);
-                    }
-                    Err(err) => {
-                        panic!("Cannot read line in {} - {}", path.display(), err);
-                    }
-                }
-            }
-        }
-        Err(err) => {
-            panic!("Cannot read file {} - {}", path.display(), err);
-        }
+/// Add up the calibration values of the lines that have one, warning about the rest.
+fn calibrate(input: &[String], mode: Mode) -> Answer {
+    let report = report(input, &ENGLISH, mode);
+    for rejected in &report.rejected {
+        warn!("{rejected}");
+    }
+    if !report.rejected.is_empty() {
+        warn!(
+            "{} of {} lines have no digit and are left out",
+            report.rejected.len(),
+            input.len()
+        );
     }
+    report.total.into()
+}
 
-    println!("{}", sum);
+/// A line with nothing that spells a digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigit {
+    /// The 1-based line number.
+    pub line: usize,
+    pub text: String,
 }
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> std::io::Result<std::io::Lines<std::io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(std::io::BufReader::new(file).lines())
+impl fmt::Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: no digit found in `{}`", self.line, self.text)
+    }
+}
+
+/// The sum of a document's calibration values, and the lines that don't have one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub total: u32,
+    /// How many lines have a calibration value.
+    pub accepted: usize,
+    pub rejected: Vec<NoDigit>,
 }
 
-/// Concatenate the first and last digit characters in the string together.
-fn secret_number(line: &String) -> u32 {
-    // contruct a map of digit matches to positions
-    let mut matches = Vec::new();
-    for (d_str, d_val) in DIGITS {
-        for (index, _) in line.match_indices(d_str) {
-            matches.push(DigitMatch {
-                digit: d_val,
-                index,
-            });
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rejected in &self.rejected {
+            writeln!(f, "{rejected}")?;
         }
+        write!(
+            f,
+            "{} of {} lines have no digit; the rest add up to {}",
+            self.rejected.len(),
+            self.accepted + self.rejected.len(),
+            self.total
+        )
     }
+}
+
+/// Concatenate the first and last digits in the string together, recognizing the spellings from
+/// `vocabulary` that `mode` allows.
+pub fn secret_number(line: &str, vocabulary: &Vocabulary, mode: Mode) -> Option<u32> {
+    Some(vocabulary.first(line, mode)? * 10 + vocabulary.last(line, mode)?)
+}
 
-    // Sort matches by index
-    matches.sort_by(|a, b| a.index.cmp(&b.index));
-    let matches = matches;
-    let first = &matches[0];
-    let last = &matches[matches.len() - 1];
-    return first.digit * 10 + last.digit;
+/// The calibration value of each line, or why it has none.
+pub fn decode(lines: &[String], vocabulary: &Vocabulary, mode: Mode) -> Vec<Result<u32, NoDigit>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            secret_number(line, vocabulary, mode).ok_or_else(|| NoDigit {
+                line: i + 1,
+                text: line.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Decode every line, adding up the values found and listing the lines without one.
+pub fn report(lines: &[String], vocabulary: &Vocabulary, mode: Mode) -> Report {
+    let mut report = Report {
+        total: 0,
+        accepted: 0,
+        rejected: Vec::new(),
+    };
+    for result in decode(lines, vocabulary, mode) {
+        match result {
+            Ok(value) => {
+                report.total += value;
+                report.accepted += 1;
+            }
+            Err(rejected) => report.rejected.push(rejected),
+        }
+    }
+    report
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day1>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day1>(2);
+    }
 
     #[test]
     fn test_secret_number() {
@@ -112,7 +174,53 @@ mod tests {
             ("1four7", 17),
             ("asixa", 66),
         ] {
-            assert_eq!(exp_res, secret_number(&line.to_string()), "line={line}");
+            assert_eq!(
+                Some(exp_res),
+                secret_number(line, &ENGLISH, Mode::Words),
+                "line={line}"
+            );
         }
     }
+
+    #[test]
+    fn test_secret_number_digits_only() {
+        for (line, exp_res) in [
+            ("1abc2", 12),
+            ("pqr3stu8vwx", 38),
+            ("treb7uchet", 77),
+            ("two1nine", 11),
+            ("eightwo3xyz4", 34),
+        ] {
+            assert_eq!(
+                Some(exp_res),
+                secret_number(line, &ENGLISH, Mode::Numerals),
+                "line={line}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let lines = ["two1nine", "", "abc", "7pqrstsixteen"].map(str::to_string);
+        assert_eq!(secret_number("abc", &ENGLISH, Mode::Words), None);
+        let decoded = decode(&lines, &ENGLISH, Mode::Numerals);
+        assert_eq!(decoded[0], Ok(11));
+        assert_eq!(
+            decoded[2],
+            Err(NoDigit {
+                line: 3,
+                text: "abc".to_string()
+            })
+        );
+
+        let report = report(&lines, &ENGLISH, Mode::Words);
+        assert_eq!(report.total, 29 + 76);
+        assert_eq!(report.accepted, 2);
+        assert_eq!(
+            report.to_string(),
+            "line 2: no digit found in ``\n\
+             line 3: no digit found in `abc`\n\
+             2 of 4 lines have no digit; the rest add up to 105"
+        );
+    }
 }
@@ -0,0 +1,186 @@
+//! Find the first and last digits in a line in one pass each, however they are spelled.
+//!
+//! The spellings are compiled into an Aho–Corasick automaton: a trie of their bytes whose missing
+//! transitions are filled in from the longest suffix that is still in the trie, so matching never
+//! backtracks and overlapping spellings like `eighthree` are all seen.
+
+use std::collections::VecDeque;
+
+/// Which spellings of a digit count.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Only numerals, like `7`.
+    Numerals,
+    /// Numerals and words, like `7` and `seven`.
+    Words,
+}
+
+impl Mode {
+    fn allows(self, spelling: &Spelling) -> bool {
+        match self {
+            Mode::Numerals => !spelling.word,
+            Mode::Words => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Spelling {
+    len: usize,
+    value: u32,
+    /// Whether this is a word rather than a numeral.
+    word: bool,
+}
+
+struct State {
+    next: [usize; 256],
+    /// The spellings ending here, longest first.
+    outputs: Vec<Spelling>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            next: [0; 256],
+            outputs: Vec::new(),
+        }
+    }
+}
+
+/// A matcher for a set of byte strings. State 0 is the root, which is never a trie child, so 0 also
+/// marks a missing child while the trie is built.
+struct Automaton {
+    states: Vec<State>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new(patterns: impl IntoIterator<Item = (Vec<u8>, Spelling)>) -> Self {
+        let mut states = vec![State::new()];
+        let mut max_len = 0;
+        for (bytes, spelling) in patterns {
+            let mut state = 0;
+            for &b in &bytes {
+                if states[state].next[b as usize] == 0 {
+                    states.push(State::new());
+                    states[state].next[b as usize] = states.len() - 1;
+                }
+                state = states[state].next[b as usize];
+            }
+            states[state].outputs.push(spelling);
+            max_len = max_len.max(bytes.len());
+        }
+
+        // Breadth first, so the state a suffix link points to is always finished first.
+        let mut suffix = vec![0; states.len()];
+        let mut queue = states[0]
+            .next
+            .iter()
+            .copied()
+            .filter(|&child| child != 0)
+            .collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let link = suffix[state];
+            let inherited = states[link].outputs.clone();
+            states[state].outputs.extend(inherited);
+            for b in 0..256 {
+                let child = states[state].next[b];
+                let fallback = states[link].next[b];
+                if child == 0 {
+                    states[state].next[b] = fallback;
+                } else {
+                    suffix[child] = fallback;
+                    queue.push_back(child);
+                }
+            }
+        }
+        Self { states, max_len }
+    }
+
+    /// The value of the match that starts first in `bytes`, or the longest of those that start
+    /// first together.
+    fn earliest(&self, bytes: impl Iterator<Item = u8>, mode: Mode) -> Option<u32> {
+        let mut state = 0;
+        let mut best: Option<(usize, u32)> = None;
+        for (i, b) in bytes.enumerate() {
+            // Matches ending here or later start after the best one.
+            if best.is_some_and(|(start, _)| i >= start + self.max_len) {
+                break;
+            }
+            state = self.states[state].next[b as usize];
+            if let Some(spelling) = self.states[state].outputs.iter().find(|s| mode.allows(s)) {
+                let start = i + 1 - spelling.len;
+                if best.is_none_or(|(best_start, _)| start <= best_start) {
+                    best = Some((start, spelling.value));
+                }
+            }
+        }
+        best.map(|(_, value)| value)
+    }
+}
+
+/// Finds digits spelled any of a fixed set of ways, reading lines from the front for the first
+/// digit and from the back for the last.
+pub struct DigitScanner {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl DigitScanner {
+    /// A scanner for `spellings` and the digits they stand for. Spellings made of ASCII digits are
+    /// numerals, and the rest are words.
+    pub fn new(spellings: &[(&str, u32)]) -> Self {
+        let patterns = spellings
+            .iter()
+            .filter(|(text, _)| !text.is_empty())
+            .map(|&(text, value)| {
+                let spelling = Spelling {
+                    len: text.len(),
+                    value,
+                    word: !text.bytes().all(|b| b.is_ascii_digit()),
+                };
+                (text.as_bytes().to_vec(), spelling)
+            })
+            .collect::<Vec<_>>();
+        let reversed = patterns
+            .iter()
+            .map(|(bytes, spelling)| (bytes.iter().rev().copied().collect(), *spelling))
+            .collect::<Vec<_>>();
+        Self {
+            forward: Automaton::new(patterns),
+            backward: Automaton::new(reversed),
+        }
+    }
+
+    /// The digit spelled starting first in `line`.
+    pub fn first(&self, line: &str, mode: Mode) -> Option<u32> {
+        self.forward.earliest(line.bytes(), mode)
+    }
+
+    /// The digit spelled ending last in `line`.
+    pub fn last(&self, line: &str, mode: Mode) -> Option<u32> {
+        self.backward.earliest(line.bytes().rev(), mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let scanner = DigitScanner::new(&[("he", 1), ("she", 2), ("his", 3), ("hers", 4)]);
+        assert_eq!(scanner.first("ushers", Mode::Words), Some(2));
+        assert_eq!(scanner.last("ushers", Mode::Words), Some(4));
+        assert_eq!(scanner.first("ahishe", Mode::Words), Some(3));
+        assert_eq!(scanner.last("ahishe", Mode::Words), Some(2));
+        assert_eq!(scanner.first("ushers", Mode::Numerals), None);
+        assert_eq!(scanner.last("", Mode::Words), None);
+
+        let scanner = DigitScanner::new(&[("8", 8), ("eight", 8), ("eighty", 80), ("three", 3)]);
+        assert_eq!(scanner.first("eighthree", Mode::Words), Some(8));
+        assert_eq!(scanner.last("eighthree", Mode::Words), Some(3));
+        assert_eq!(scanner.first("eighty", Mode::Words), Some(80));
+        assert_eq!(scanner.last("x8eighthree", Mode::Numerals), Some(8));
+    }
+}
@@ -0,0 +1,184 @@
+//! The words a calibration document may spell digits with.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::scanner::{DigitScanner, Mode};
+
+/// The names [`Vocabulary::builtin`] knows.
+pub const BUILTINS: [&str; 5] = ["english", "french", "german", "spanish", "ordinals"];
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+const ORDINALS: [&str; 9] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+];
+
+/// Words for digits, on top of the numerals `0` to `9` which every vocabulary has.
+pub struct Vocabulary {
+    name: String,
+    words: Vec<(String, u32)>,
+    scanner: DigitScanner,
+}
+
+impl Vocabulary {
+    /// A vocabulary of `words` already checked to be non-empty, not numerals, and standing for
+    /// digits.
+    fn new<S: Into<String>>(
+        name: impl Into<String>,
+        words: impl IntoIterator<Item = (S, u32)>,
+    ) -> Self {
+        let words = words
+            .into_iter()
+            .map(|(word, digit)| (word.into(), digit))
+            .collect::<Vec<_>>();
+        let numerals = (0..10).map(|digit| (digit.to_string(), digit));
+        let spellings = numerals.chain(words.iter().cloned()).collect::<Vec<_>>();
+        let spellings = spellings
+            .iter()
+            .map(|(text, digit)| (text.as_str(), *digit))
+            .collect::<Vec<_>>();
+        Self {
+            name: name.into(),
+            scanner: DigitScanner::new(&spellings),
+            words,
+        }
+    }
+
+    /// `words` spelling out one to nine, in order.
+    fn one_to_nine(name: &str, words: [&str; 9]) -> Self {
+        Self::new(name, words.into_iter().zip(1..))
+    }
+
+    /// The words from the puzzle text.
+    pub fn english() -> Self {
+        Self::one_to_nine("english", ENGLISH)
+    }
+
+    pub fn french() -> Self {
+        Self::one_to_nine("french", FRENCH)
+    }
+
+    pub fn german() -> Self {
+        Self::one_to_nine("german", GERMAN)
+    }
+
+    pub fn spanish() -> Self {
+        Self::one_to_nine("spanish", SPANISH)
+    }
+
+    /// English ordinals, `first` to `ninth`.
+    pub fn ordinals() -> Self {
+        Self::one_to_nine("ordinals", ORDINALS)
+    }
+
+    /// The built-in vocabulary called `name`, one of [`BUILTINS`].
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Self::english()),
+            "french" => Some(Self::french()),
+            "german" => Some(Self::german()),
+            "spanish" => Some(Self::spanish()),
+            "ordinals" => Some(Self::ordinals()),
+            _ => None,
+        }
+    }
+
+    /// Parse a TOML table of words and the digits they stand for, like `eins = 1`.
+    pub fn parse(name: impl Into<String>, text: &str) -> Result<Self, String> {
+        let table = toml::from_str::<BTreeMap<String, u32>>(text).map_err(|err| err.to_string())?;
+        if let Some((word, digit)) = table.iter().find(|&(_, &digit)| digit > 9) {
+            return Err(format!("`{word}` stands for {digit}, which is not a digit"));
+        }
+        if table.contains_key("") {
+            return Err("words cannot be empty".to_string());
+        }
+        if let Some(word) = table
+            .keys()
+            .find(|word| word.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return Err(format!("`{word}` is a numeral, not a word"));
+        }
+        Ok(Self::new(name, table))
+    }
+
+    /// Load a vocabulary file written as [`Vocabulary::parse`] expects, named after the file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {} - {}", path.display(), err))?;
+        let name = path
+            .file_stem()
+            .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+        Self::parse(name, &text).map_err(|err| format!("cannot parse {} - {}", path.display(), err))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The words and the digits they stand for, without the numerals.
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    /// The digit spelled starting first in `line`.
+    pub fn first(&self, line: &str, mode: Mode) -> Option<u32> {
+        self.scanner.first(line, mode)
+    }
+
+    /// The digit spelled ending last in `line`.
+    pub fn last(&self, line: &str, mode: Mode) -> Option<u32> {
+        self.scanner.last(line, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins() {
+        for name in BUILTINS {
+            let vocabulary = Vocabulary::builtin(name).unwrap();
+            assert_eq!(vocabulary.name(), name);
+            assert_eq!(vocabulary.words().len(), 9);
+        }
+        assert!(Vocabulary::builtin("klingon").is_none());
+
+        let german = Vocabulary::german();
+        assert_eq!(german.first("xfünfzwei7", Mode::Words), Some(5));
+        assert_eq!(german.last("xfünfzwei7", Mode::Words), Some(7));
+        assert_eq!(german.first("xfünfzwei7", Mode::Numerals), Some(7));
+        let ordinals = Vocabulary::ordinals();
+        assert_eq!(ordinals.first("eighthirdx", Mode::Words), Some(8));
+        assert_eq!(ordinals.last("eighthirdx", Mode::Words), Some(3));
+    }
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse("test", "zero = 0\n\"dos\" = 2 # Spanish\n").unwrap();
+        assert_eq!(
+            vocabulary.words(),
+            [("dos".to_string(), 2), ("zero".to_string(), 0)]
+        );
+        assert_eq!(vocabulary.first("azeros", Mode::Words), Some(0));
+        assert_eq!(vocabulary.last("dos1zero", Mode::Words), Some(0));
+
+        assert!(Vocabulary::parse("test", "ten = 10").is_err());
+        assert!(Vocabulary::parse("test", "\"\" = 1").is_err());
+        assert!(Vocabulary::parse("test", "\"12\" = 3").is_err());
+        assert!(Vocabulary::parse("test", "one 1").is_err());
+        assert!(Vocabulary::load(Path::new("does/not/exist.toml")).is_err());
+    }
+}
@@ -1,9 +1,58 @@
 // https://adventofcode.com/2023/day/10
 
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
-mod part2_bad;
 
-pub fn run() {
-    part2::run();
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("example.txt"),
+            part1: Some("8"),
+            part2: None,
+        },
+        Example {
+            input: include_str!("example2.txt"),
+            part1: None,
+            part2: Some("4"),
+        },
+    ];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let map = Grid::parse(10, &lines, |c| "S.|-LJ7F".contains(c).then_some(c))?;
+        if map.position(|&c| c == 'S').is_none() {
+            return Err(InputError::malformed(10, "no starting tile `S`"));
+        }
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day10>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day10>(2);
+    }
 }
@@ -1,8 +1,51 @@
-// https://adventofcode.com/2023/day/X
+// https://adventofcode.com/2023/day/11
+
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputResult, Solution};
 
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<bool>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("374"),
+        part2: Some("82000210"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Grid::parse(11, &lines, |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day11>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day11>(2);
+    }
 }
@@ -2,9 +2,7 @@ use std::cmp::max;
 use std::cmp::min;
 use std::fmt;
 
-use itertools::Itertools;
-
-use crate::common::get_input;
+use crate::common::grid::Grid;
 
 #[derive(Copy, Clone)]
 struct Galaxy {
@@ -26,52 +24,32 @@ impl Galaxy {
     }
 }
 
-fn find_occupied_rows_cols(input: &Vec<String>) -> (Vec<bool>, Vec<bool>) {
-    let map_width = input[0].len();
-    let map_height = input.len();
-
-    let mut occupied_cols = vec![false; map_width];
-    let mut occupied_rows = vec![false; map_height];
+fn find_occupied_rows_cols(image: &Grid<bool>) -> (Vec<bool>, Vec<bool>) {
+    let occupied_rows = image.rows().map(|row| row.contains(&true)).collect();
+    let occupied_cols = image.cols().map(|mut col| col.any(|&cell| cell)).collect();
 
-    for r in 0..map_height {
-        let row_chars = input.get(r).unwrap().chars().collect_vec();
-        for c in 0..map_width {
-            let cell = row_chars[c];
-            if cell == '#' {
-                // mark occupied rows and cols
-                occupied_rows[r] = true;
-                occupied_cols[c] = true;
-            }
-        }
-    }
-
-    return (occupied_rows, occupied_cols);
+    (occupied_rows, occupied_cols)
 }
 
 fn expanded_galaxy_locations(
-    input: &Vec<String>,
-    occupied_rows: &Vec<bool>,
-    occupied_cols: &Vec<bool>,
+    image: &Grid<bool>,
+    occupied_rows: &[bool],
+    occupied_cols: &[bool],
 ) -> Vec<Galaxy> {
     // let mut gid = 1;
-    let map_width = input[0].len();
-    let map_height = input.len();
-
     let mut galaxies = Vec::<Galaxy>::new();
 
     let mut exp_r = 0;
-    for r in 0..map_height {
+    for (r, row) in image.rows().enumerate() {
         let mut exp_c = 0;
         if !occupied_rows[r] {
             exp_r += 1
         }
-        let row_chars = input.get(r).unwrap().chars().collect_vec();
-        for c in 0..map_width {
+        for (c, &cell) in row.iter().enumerate() {
             if !occupied_cols[c] {
                 exp_c += 1
             }
-            let cell = row_chars[c];
-            if cell == '#' {
+            if cell {
                 // Save this galaxy's cocrdinates
                 galaxies.push(Galaxy {
                     // id: gid,
@@ -84,26 +62,22 @@ fn expanded_galaxy_locations(
         }
         exp_r += 1;
     }
-    return galaxies;
+    galaxies
 }
 
-pub fn run() {
-    let input = get_input("src/day11/input1.txt");
-
+pub fn solve(input: &Grid<bool>) -> u64 {
     // First pass - determine expansion
-    let (occupied_rows, occupied_cols) = find_occupied_rows_cols(&input);
+    let (occupied_rows, occupied_cols) = find_occupied_rows_cols(input);
 
     // Second pass - determing galaxy locations after expansion
-    let galaxies = expanded_galaxy_locations(&input, &occupied_rows, &occupied_cols);
+    let galaxies = expanded_galaxy_locations(input, &occupied_rows, &occupied_cols);
 
     // For each pair of galaxies, find the manhattan distance between them.
-    let ans: u64 = (0..galaxies.len())
+    (0..galaxies.len())
         .map(|i| {
             ((i + 1)..galaxies.len())
                 .map(|j| galaxies[i].manhattan_distance(&galaxies[j]))
                 .sum::<u64>()
         })
-        .sum();
-
-    println!("{ans}");
+        .sum()
 }
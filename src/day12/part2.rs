@@ -1,8 +1,8 @@
 use std::{collections::HashMap, sync::Arc};
 
-use itertools::Itertools;
+use log::trace;
 
-use crate::common::get_input;
+use super::Record;
 
 // WIP - not solved yet
 
@@ -28,7 +28,7 @@ struct CacheKey {
 impl CacheKey {
     fn new(template: Arc<String>, nums: &[usize], min_start: usize) -> Self {
         Self {
-            template: template,
+            template,
             nums: nums.to_vec(),
             min_start,
         }
@@ -44,13 +44,13 @@ fn process_template(
 ) -> u32 {
     let key = CacheKey::new(Arc::clone(&template), nums, min_start);
     if let Some(cached) = cache.map.get(&key) {
-        // println!("Cache hit!");
+        trace!("Cache hit!");
         return *cached;
     }
 
-    if nums.len() == 0 {
+    if nums.is_empty() {
         let mut str_builder = result_str.clone();
-        for i in str_builder.len()..=template.len() {
+        for _ in str_builder.len()..=template.len() {
             str_builder += ".";
         }
         // Check the template against str_builder
@@ -58,17 +58,17 @@ fn process_template(
             let t = template.chars().nth(i).unwrap();
             let my = str_builder.chars().nth(i).unwrap();
             if t == '.' && my == '#' || t == '#' && my != '#' {
-                // println!("Mismatch - template={template}, str = {str_builder}");
+                trace!("Mismatch - template={template}, str = {str_builder}");
                 return 0;
             }
         }
 
         cache.map.insert(key, 1);
-        // println!("{}", str_builder);
+        trace!("{}", str_builder);
         return 1;
     }
     let my_num = nums[0];
-    if min_start + my_num - 1 >= template.len() {
+    if min_start + my_num > template.len() {
         return 0;
     }
 
@@ -94,21 +94,21 @@ fn process_template(
             continue;
         }
 
-        // println!(
-        //     "{:?} can go at {:?}..={:?} in {:?}, followed by {:?}",
-        //     my_num,
-        //     start,
-        //     start + my_num - 1,
-        //     template,
-        //     nums_r
-        // );
+        trace!(
+            "{:?} can go at {:?}..={:?} in {:?}, followed by {:?}",
+            my_num,
+            start,
+            start + my_num - 1,
+            template,
+            nums_r
+        );
 
         // Process the rest of the numbers after locking in this position
         let mut str_builder = result_str.clone();
-        for i in min_start..start {
+        for _ in min_start..start {
             str_builder += ".";
         }
-        for i in start..(start + my_num) {
+        for _ in start..(start + my_num) {
             str_builder += "#";
         }
         str_builder += ".";
@@ -120,7 +120,7 @@ fn process_template(
             cache,
         );
     }
-    return n_possibilities;
+    n_possibilities
 }
 
 fn solution(template: Arc<String>, nums: &[usize], cache: &mut Cache) -> u32 {
@@ -133,57 +133,47 @@ fn solution(template: Arc<String>, nums: &[usize], cache: &mut Cache) -> u32 {
     let mut str_builder = "".to_owned();
     match min_start {
         Some(min_start) => {
-            for i in 0..min_start {
+            for _ in 0..min_start {
                 str_builder += ".";
             }
-            return process_template(template, &nums[..], min_start, str_builder, cache);
-        }
-        None => {
-            return 0;
+            process_template(template, nums, min_start, str_builder, cache)
         }
+        None => 0,
     }
 }
 
 fn unfold_template(template: String) -> String {
     const N_COPIES: usize = 5;
     let mut unfolded = template.clone();
-    for i in 1..N_COPIES {
-        unfolded.push_str("?");
+    for _ in 1..N_COPIES {
+        unfolded.push('?');
         unfolded.push_str(&template);
     }
-    return unfolded;
+    unfolded
 }
 
 fn unfold_nums(nums: Vec<usize>) -> Vec<usize> {
     const N_COPIES: usize = 5;
-    let mut unfolded = Vec::new();
-    unfolded.reserve(nums.len() * N_COPIES);
+    let mut unfolded = Vec::with_capacity(nums.len() * N_COPIES);
     for _ in 0..N_COPIES {
         for n in nums.iter() {
             unfolded.push(*n);
         }
     }
-    return unfolded;
+    unfolded
 }
 
-pub fn run() {
-    let input = get_input("src/day12/input0.txt");
-
+pub fn solve(input: &[Record]) -> u32 {
     let mut sum = 0;
-    for line in input.iter() {
-        let tokens = line.split_ascii_whitespace().collect_vec();
-        let template = unfold_template(tokens[0].to_string());
-        let nums: Vec<usize> = tokens[1]
-            .split(",")
-            .map(|n| n.parse().unwrap())
-            .collect_vec();
-        let nums = unfold_nums(nums);
+    for record in input.iter() {
+        let template = unfold_template(record.template.clone());
+        let nums = unfold_nums(record.nums.clone());
 
         let mut cache = Cache::new();
         sum += solution(Arc::new(template.to_string()), &nums, &mut cache);
     }
 
-    println!("{sum}");
+    sum
 }
 
 #[cfg(test)]
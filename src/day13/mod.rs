@@ -1,8 +1,58 @@
-// https://adventofcode.com/2023/day/X
+// https://adventofcode.com/2023/day/13
+
+use crate::common::grid::Grid;
+use crate::common::parse;
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
 
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// Patterns of ash and rocks, separated by blank lines in the input.
+    type Input = Vec<Grid<char>>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("405"),
+        part2: Some("400"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let patterns = parse::blocks(&lines)
+            .map(|(start, block)| {
+                Grid::parse(13, block, |c| matches!(c, '.' | '#').then_some(c))
+                    .map_err(|err| err.offset_lines(start))
+            })
+            .collect::<InputResult<Vec<_>>>()?;
+        if patterns.is_empty() {
+            return Err(InputError::malformed(13, "no patterns found"));
+        }
+        Ok(patterns)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day13>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day13>(2);
+    }
 }
@@ -1,8 +1,48 @@
-// https://adventofcode.com/2023/day/X
+// https://adventofcode.com/2023/day/14
+
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputResult, Solution};
 
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("136"),
+        part2: Some("64"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Grid::parse(14, &lines, |c| matches!(c, '.' | 'O' | '#').then_some(c))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day14>(1);
+    }
+
+    #[test]
+    #[ignore = "simulates all 1,000,000,000 cycles one at a time"]
+    fn test_part2_examples() {
+        check_examples::<Day14>(2);
+    }
 }
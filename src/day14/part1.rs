@@ -1,82 +1,62 @@
-use itertools::Itertools;
+use crate::common::grid::{Grid, Pos};
+use crate::common::visualize::{self, Color, Frame};
 
-use crate::common::get_input;
-
-// Assume input is rectangular
-fn rotate_input_90cw(input: Vec<String>) -> Vec<String> {
-    if input.len() == 0 {
-        return vec![];
-    }
-    let w = input.len();
-    let h = input[0].len();
-    let mut output = vec![vec!['.'; h]; w];
-    for r in 0..h {
-        let in_chars = input[r].chars().collect_vec();
-        for c in 0..w {
-            output[c][h - 1 - r] = in_chars[c];
+// Roll every round boulder in the row as far right as it goes.
+fn shift_row(row: &mut [char]) {
+    let w = row.len();
+    let mut n_left = 0;
+    for c in 0..=w {
+        if c == w || row[c] == '#' {
+            // Shift boulders on the left
+            row[c - n_left..c].fill('O');
+            for d in (0..(c - n_left)).rev() {
+                if row[d] == '#' {
+                    break;
+                }
+                row[d] = '.';
+            }
+            n_left = 0;
+        } else if row[c] == 'O' {
+            n_left += 1;
         }
     }
-    let output = output
-        .iter()
-        .map(|r| r.into_iter().collect::<String>())
-        .collect_vec();
-    return output;
 }
 
-fn shift_round_boulders(input: Vec<String>) -> Vec<String> {
-    let w = input.len();
-    let h = input[0].len();
-    let mut output = vec![vec!['.'; w]; h];
-    for r in 0..h {
-        let row = input[r].chars().collect_vec();
-        let mut n_left = 0;
-        for c in 0..=w {
-            if c < w {
-                output[r][c] = row[c];
-            }
-            if c == w || row[c] == '#' {
-                // Shift boulders on the left
-                for b in (c - n_left)..c {
-                    output[r][b] = 'O';
-                }
-                for d in (0..(c - n_left)).rev() {
-                    if output[r][d] == '#' {
-                        break;
-                    }
-                    output[r][d] = '.';
-                }
-                n_left = 0;
-            } else if row[c] == 'O' {
-                n_left += 1;
-            }
-        }
+fn shift_round_boulders(platform: &mut Grid<char>) {
+    record_tilt(platform, None);
+    for r in 0..platform.height() {
+        shift_row(platform.row_mut(r));
+        record_tilt(platform, Some(r));
     }
-    let output = output
-        .iter()
-        .map(|r| r.into_iter().collect::<String>())
-        .collect_vec();
-    return output;
 }
 
-fn calculate_load(input: Vec<String>) -> i32 {
-    let w = input.len();
-    let h = input[0].len();
+/// Record the rotated platform turned back with north up, highlighting the column just tilted.
+fn record_tilt(platform: &Grid<char>, tilted: Option<usize>) {
+    visualize::record(|| {
+        let height = platform.width();
+        let column = tilted
+            .into_iter()
+            .flat_map(|col| (0..height).map(move |row| Pos::new(row, col)));
+        Frame::new("Tilting north", platform.rotate_ccw())
+            .highlight(column, Color::Yellow)
+            .counter("columns tilted", tilted.map_or(0, |col| col + 1))
+            .counter("load", calculate_load(platform))
+    });
+}
+
+fn calculate_load(platform: &Grid<char>) -> i32 {
     let mut load = 0i32;
-    for r in 0..h {
-        let row = input[r].chars().collect_vec();
-        for c in 0..w {
-            if row[c] == 'O' {
-                load += (c + 1) as i32;
-            }
+    for (pos, &cell) in platform.iter() {
+        if cell == 'O' {
+            load += (pos.col + 1) as i32;
         }
     }
-    return load;
+    load
 }
 
-pub fn run() {
-    let input = get_input("src/day14/input1.txt");
-    let rotated = rotate_input_90cw(input);
-    let shifted = shift_round_boulders(rotated);
-    let load = calculate_load(shifted);
-    println!("{load}");
+pub fn solve(input: &Grid<char>) -> i32 {
+    // After a clockwise turn, north is on the right.
+    let mut rotated = input.rotate_cw();
+    shift_round_boulders(&mut rotated);
+    calculate_load(&rotated)
 }
@@ -1,8 +1,8 @@
-use indicatif::ProgressBar;
-use itertools::Itertools;
+use log::trace;
 use rayon::prelude::*;
 
-use crate::common::get_input;
+use crate::common::grid::Grid;
+use crate::common::progress::Progress;
 
 const GAP: u8 = 0;
 const ROUND_BOULDER: u8 = 1;
@@ -15,9 +15,7 @@ fn shift_slice(slice: &mut [u8], n: usize) {
         let cur = if c == n { UNKNOWN } else { slice[c] };
         if c == n || cur == SQUARE_BOULDER {
             // Shift boulders on the left
-            for b in (c - n_left)..c {
-                slice[b] = ROUND_BOULDER;
-            }
+            slice[c - n_left..c].fill(ROUND_BOULDER);
             for d in (0..(c - n_left)).rev() {
                 if slice[d] == SQUARE_BOULDER {
                     break;
@@ -32,100 +30,60 @@ fn shift_slice(slice: &mut [u8], n: usize) {
 }
 
 // Shift boulders from the left to the right. O(n^2).
-fn shift_round_boulders(matrix: &mut Vec<u8>, n: usize) {
-    matrix.par_chunks_mut(n).for_each(|slice| {
+fn shift_round_boulders(matrix: &mut Grid<u8>) {
+    matrix.par_rows_mut().for_each(|slice| {
+        let n = slice.len();
         shift_slice(slice, n);
     });
 }
 
-fn calculate_load(matrix: &Vec<u8>, n: usize) -> i32 {
+fn calculate_load(matrix: &Grid<u8>) -> i32 {
     let mut load = 0i32;
-    for r in 0..n {
-        for c in 0..n {
-            let cur = matrix[to_mat_coord(r, c, n)];
-            if cur == ROUND_BOULDER {
-                load += (c + 1) as i32;
-            }
-        }
-    }
-    return load;
-}
-
-fn build_matrix(input: Vec<String>, n: usize) -> Vec<u8> {
-    let mut matrix = vec![GAP; n * n];
-
-    for r in 0..n {
-        let in_row_chars = input[r].chars().collect_vec();
-        for c in 0..n {
-            let in_char = in_row_chars[c];
-            let mat_char = match in_char {
-                'O' => ROUND_BOULDER,
-                '#' => SQUARE_BOULDER,
-                _ => continue,
-            };
-            matrix[to_mat_coord(r, c, n)] = mat_char;
+    for (pos, &cur) in matrix.iter() {
+        if cur == ROUND_BOULDER {
+            load += (pos.col + 1) as i32;
         }
     }
-    return matrix;
-}
-
-fn to_mat_coord(r: usize, c: usize, n: usize) -> usize {
-    r * n + c
+    load
 }
 
-// Rotate the matrix in-place, O(n^2) time
-fn rotate_matrix_90cw(matrix: &mut Vec<u8>, n: usize) {
-    for i in 0..(n / 2) {
-        for j in i..(n - i - 1) {
-            let top_left = to_mat_coord(i, j, n);
-            let top_right = to_mat_coord(j, n - 1 - i, n);
-            let bottom_right = to_mat_coord(n - 1 - i, n - 1 - j, n);
-            let bottom_left = to_mat_coord(n - 1 - j, i, n);
-
-            let temp = matrix[top_left];
-            matrix[top_left] = matrix[bottom_left];
-            matrix[bottom_left] = matrix[bottom_right];
-            matrix[bottom_right] = matrix[top_right];
-            matrix[top_right] = temp;
-        }
-    }
+fn build_matrix(input: &Grid<char>) -> Grid<u8> {
+    input.map(|&c| match c {
+        'O' => ROUND_BOULDER,
+        '#' => SQUARE_BOULDER,
+        _ => GAP,
+    })
 }
 
-fn print_matrix(matrix: &Vec<u8>, n: usize) {
-    for i in 0..n {
-        for j in 0..n {
-            let c = match matrix[i * n + j] {
-                GAP => '.',
-                ROUND_BOULDER => 'O',
-                SQUARE_BOULDER => '#',
-                _ => panic!(),
-            };
-            print!("{c}");
-        }
-        println!();
-    }
-    println!();
+fn log_matrix(matrix: &Grid<u8>) {
+    trace!(
+        "\n{}",
+        matrix.render(|&cell| match cell {
+            GAP => '.',
+            ROUND_BOULDER => 'O',
+            SQUARE_BOULDER => '#',
+            _ => panic!(),
+        })
+    );
 }
 
-pub fn run() {
-    let input = get_input("src/day14/input1.txt");
-
+pub fn solve(input: &Grid<char>) -> i32 {
     const CYCLES: u64 = 1_000_000_000;
-    let bar = ProgressBar::new(CYCLES);
-    let n = input.len();
-    assert_ne!(n, 0, "Input must be non-empty");
-    assert_eq!(n, input[0].len(), "Input must be square");
+    let progress = Progress::new("Spin cycles", CYCLES);
 
-    let mut matrix = build_matrix(input, n);
+    let mut matrix = build_matrix(input);
     for _ in 0..CYCLES {
         for _ in 0..4 {
-            rotate_matrix_90cw(&mut matrix, n);
-            shift_round_boulders(&mut matrix, n);
-            // print_matrix(&matrix, n);
+            if matrix.width() == matrix.height() {
+                matrix.rotate_cw_mut();
+            } else {
+                matrix = matrix.rotate_cw();
+            }
+            shift_round_boulders(&mut matrix);
+            log_matrix(&matrix);
         }
-        bar.inc(1);
+        progress.inc(1);
     }
-    bar.finish();
-    let load = calculate_load(&matrix, n);
-    println!("{load}");
+    progress.finish();
+    calculate_load(&matrix)
 }
@@ -1,8 +1,118 @@
 // https://adventofcode.com/2023/day/15
 
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `=`, with the focal length of the lens to put in.
+    Add(u8),
+    /// `-`
+    Remove,
+}
+
+/// One step of the initialization sequence, like `rn=1` or `cm-`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub text: String,
+    pub label: String,
+    pub operation: Operation,
+}
+
+fn parse_step(line: Line, text: &str) -> InputResult<Step> {
+    let invalid = || line.error(text, "expected `<label>-` or `<label>=<focal length>`");
+    let (label, operation) = text.split_at(text.find(['=', '-']).ok_or_else(invalid)?);
+    let operation = if operation == "-" {
+        Operation::Remove
+    } else if let Some(focal_length) = operation.strip_prefix('=') {
+        Operation::Add(line.token(focal_length)?)
+    } else {
+        return Err(invalid());
+    };
+    Ok(Step {
+        text: text.to_string(),
+        label: label.to_string(),
+        operation,
+    })
+}
+
+/// The comma-separated steps, ignoring line breaks between them.
+fn parse_steps(line: Line) -> InputResult<Vec<Step>> {
+    line.text
+        .split(',')
+        .filter(|text| !text.is_empty())
+        .map(|text| parse_step(line, text))
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("1320"),
+        part2: Some("145"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Ok(parse::each_line(15, &lines, parse_steps)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, get_input_from_str, InputError};
+
+    #[test]
+    fn test_parse() {
+        let steps = Day15::parse(get_input_from_str("rn=1,cm-\nqp=3")).unwrap();
+        assert_eq!(
+            steps[0],
+            Step {
+                text: "rn=1".to_string(),
+                label: "rn".to_string(),
+                operation: Operation::Add(1)
+            }
+        );
+        assert_eq!(steps[1].operation, Operation::Remove);
+        assert_eq!(steps[2].label, "qp");
+
+        assert!(matches!(
+            Day15::parse(get_input_from_str("rn=1,cm")),
+            Err(InputError::Parse {
+                line: 1,
+                column: 6,
+                ..
+            })
+        ));
+        assert!(Day15::parse(get_input_from_str("rn=x")).is_err());
+        assert!(Day15::parse(get_input_from_str("rn-1")).is_err());
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day15>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day15>(2);
+    }
 }
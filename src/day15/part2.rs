@@ -1,11 +1,8 @@
 use std::fmt;
 
-use once_cell::sync::Lazy;
-use regex::Regex;
+use log::debug;
 
-use crate::common::get_input;
-
-static STEP_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"([a-z]+)((-)|(=)(\d+))").unwrap());
+use super::{Operation, Step};
 
 #[derive(Clone)]
 struct Lens {
@@ -39,9 +36,9 @@ impl LensBox {
         }
     }
 
-    fn add_or_replace(&mut self, label: &String, focal_length: u8) {
+    fn add_or_replace(&mut self, label: &str, focal_length: u8) {
         for lens in self.lenses.iter_mut() {
-            if lens.label == *label {
+            if lens.label == label {
                 // Replace lens if found
                 lens.focal_length = focal_length;
                 return;
@@ -50,13 +47,13 @@ impl LensBox {
 
         // Add lens if not found
         self.lenses.push(Lens {
-            label: label.clone(),
+            label: label.to_string(),
             focal_length,
         });
     }
 
-    fn remove(&mut self, label: &String) {
-        let index = self.lenses.iter().position(|l| l.label == *label);
+    fn remove(&mut self, label: &str) {
+        let index = self.lenses.iter().position(|l| l.label == label);
         if let Some(index) = index {
             self.lenses.remove(index);
         }
@@ -85,69 +82,26 @@ impl fmt::Debug for LensBox {
     }
 }
 
-#[derive(Debug)]
-enum StepType {
-    Add(u8),
-    Remove,
-}
-
-#[derive(Debug)]
-struct Step {
-    label: String,
-    step_type: StepType,
-}
-
-impl Step {
-    fn parse(step_str: &str) -> Self {
-        let re = &STEP_REGEX;
-        let caps = re.captures(step_str).unwrap();
-        let label = caps.get(1).unwrap().as_str();
-        let minus = caps.get(3).and_then(|f| Some(f.as_str()));
-        let eq = caps.get(4).and_then(|f| Some(f.as_str()));
-        let focal_length = caps
-            .get(5)
-            .and_then(|f| Some(f.as_str().parse::<u8>().unwrap()));
-
-        if let Some(minus) = minus {
-            return Self {
-                label: label.to_string(),
-                step_type: StepType::Remove,
-            };
-        } else if let (Some(eq), Some(focal_length)) = (eq, focal_length) {
-            return Self {
-                label: label.to_string(),
-                step_type: StepType::Add(focal_length),
-            };
-        } else {
-            panic!("Invalid step_str: {step_str}");
-        }
-    }
-}
-
 fn hash(step_input: &str) -> u8 {
     step_input
         .bytes()
-        .fold(0, |acc, x| ((((acc as u32) + (x as u32)) * 17) % 256)) as u8
+        .fold(0, |acc, x| ((acc + (x as u32)) * 17) % 256) as u8
 }
 
-pub fn run() {
-    let input = get_input("src/day15/input1.txt");
-    let input_steps = input[0].split(',');
-
+pub fn solve(input: &[Step]) -> u64 {
     let mut lens_boxes = Vec::new();
     for i in 0..=255 {
         lens_boxes.push(LensBox::new(i));
     }
 
-    let steps = input_steps.map(|step_str| Step::parse(step_str));
-    for step in steps {
+    for step in input {
         let box_key = hash(&step.label);
         let lens_box = &mut lens_boxes[box_key as usize];
-        match step.step_type {
-            StepType::Add(focal_length) => {
+        match step.operation {
+            Operation::Add(focal_length) => {
                 lens_box.add_or_replace(&step.label, focal_length);
             }
-            StepType::Remove => {
+            Operation::Remove => {
                 lens_box.remove(&step.label);
             }
         }
@@ -157,9 +111,9 @@ pub fn run() {
     for b in lens_boxes {
         if !b.is_empty() {
             focusing_power += b.focusing_power();
-            println!("{:?}", b);
+            debug!("{:?}", b);
         }
     }
 
-    println!("{}", focusing_power);
+    focusing_power
 }
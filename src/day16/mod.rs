@@ -1,8 +1,49 @@
 // https://adventofcode.com/2023/day/16
 
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("46"),
+        part2: Some("51"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Grid::parse(16, &lines, |c| {
+            matches!(c, '.' | '/' | '\\' | '|' | '-').then_some(c)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day16>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day16>(2);
+    }
 }
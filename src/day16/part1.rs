@@ -1,29 +1,10 @@
-use core::fmt;
 use std::collections::{HashSet, VecDeque};
 
-use itertools::Itertools;
+use log::trace;
 
-use crate::common::get_input;
-
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    row: u8,
-    col: u8,
-}
-
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({},{})", self.row, self.col)
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+use crate::common::geometry::Direction;
+use crate::common::grid::{Grid, Pos};
+use crate::common::visualize::{self, Color, Frame};
 
 #[derive(Clone, Copy)]
 enum MirrorDirection {
@@ -56,130 +37,23 @@ impl TileType {
     }
 }
 
-struct Tile {
-    point: Point,
-    tile_type: TileType,
-}
-
-impl Tile {
-    fn new(chr: char, row: u8, col: u8) -> Self {
-        let tile_type = TileType::new(chr);
-        let point = Point { row, col };
-        Self { tile_type, point }
-    }
-}
-
-struct Map {
-    width: u8,
-    height: u8,
-    tiles: Vec<Vec<Tile>>,
-}
-
-impl Map {
-    fn new(input: Vec<String>) -> Self {
-        assert!(
-            input.len() > 0 && input[0].len() > 0,
-            "Map must be at least 1x1"
-        );
-        let height = input.len();
-        let width = input[0].len();
-        assert!(
-            height <= 256 && width <= 256,
-            "Map must be 256x256 or smaller"
-        );
-
-        let mut tiles: Vec<Vec<Tile>> = Vec::new();
-        tiles.reserve(height);
-        for r in 0..height {
-            assert_eq!(width, input[0].len(), "Map must be rectangular");
-            let row_chars = input[r].chars().collect_vec();
-
-            let mut row = Vec::new();
-            row.reserve(width);
-            for c in 0..width {
-                let chr = row_chars[c];
-                row.push(Tile::new(chr, r as u8, c as u8));
-            }
-            tiles.push(row);
-        }
-        Self {
-            width: width as u8,
-            height: height as u8,
-            tiles,
-        }
-    }
-
-    fn next_point(&self, start: &Point, direction: &Direction) -> Option<Point> {
-        use Direction::*;
-        match direction {
-            Up => {
-                if start.row == 0 {
-                    None
-                } else {
-                    Some(Point {
-                        row: start.row - 1,
-                        col: start.col,
-                    })
-                }
-            }
-            Right => {
-                if start.col == self.width - 1 {
-                    None
-                } else {
-                    Some(Point {
-                        row: start.row,
-                        col: start.col + 1,
-                    })
-                }
-            }
-            Down => {
-                if start.row == self.height - 1 {
-                    None
-                } else {
-                    Some(Point {
-                        row: start.row + 1,
-                        col: start.col,
-                    })
-                }
-            }
-            Left => {
-                if start.col == 0 {
-                    None
-                } else {
-                    Some(Point {
-                        row: start.row,
-                        col: start.col - 1,
-                    })
-                }
-            }
-        }
-    }
-
-    fn tile_at(&self, point: &Point) -> Option<&Tile> {
-        self.tiles.get(point.row as usize)?.get(point.col as usize)
-    }
-
-    fn next_tile(&self, start: &Point, direction: &Direction) -> Option<&Tile> {
-        let next_point = self.next_point(start, direction)?;
-        self.tile_at(&next_point)
-    }
-}
+type Map = Grid<TileType>;
 
 struct Beam {
     id: u64,
-    point: Point,
+    point: Pos,
     direction: Direction,
     alive: bool,
 }
 
 impl Default for Beam {
     fn default() -> Self {
-        Self::new(0, Point { row: 0, col: 0 }, Direction::Right)
+        Self::new(0, Pos::new(0, 0), Direction::Right)
     }
 }
 
 impl Beam {
-    fn new(id: u64, point: Point, direction: Direction) -> Self {
+    fn new(id: u64, point: Pos, direction: Direction) -> Self {
         Self {
             id,
             point,
@@ -190,9 +64,9 @@ impl Beam {
 
     /// Transform this beam, and maybe return a spawned beam as well
     fn transform(&mut self, map: &Map) -> Option<Beam> {
-        let current_tile = map.tile_at(&self.point)?;
+        let current_tile = map.get(self.point)?;
         let mut new_beam: Option<Beam> = None;
-        match current_tile.tile_type {
+        match current_tile {
             TileType::Empty => {
                 // Continue in same direction
             }
@@ -234,13 +108,13 @@ impl Beam {
             }
         }
 
-        if let Some(point) = map.next_point(&self.point, &self.direction) {
+        if let Some(point) = map.step(self.point, self.direction) {
             self.point = point;
         } else {
             self.die();
         }
 
-        return new_beam;
+        new_beam
     }
 
     fn spawn_new_beam(&self, direction: Direction) -> Beam {
@@ -248,17 +122,15 @@ impl Beam {
     }
 
     fn die(&mut self) {
-        println!("Beam {} dies", self.id);
+        trace!("Beam {} dies", self.id);
         self.alive = false;
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day16/input1.txt");
-
-    let map = Map::new(input);
-    let mut visited_points = HashSet::<Point>::new();
-    let mut visited_points_dirs = HashSet::<(Point, Direction)>::new();
+pub fn solve(input: &Grid<char>) -> usize {
+    let map = input.map(|&chr| TileType::new(chr));
+    let mut visited_points = HashSet::<Pos>::new();
+    let mut visited_points_dirs = HashSet::<(Pos, Direction)>::new();
     let mut beam_q = VecDeque::<Beam>::new();
     beam_q.push_back(Beam::default());
 
@@ -272,17 +144,21 @@ pub fn run() {
         visited_points.insert(beam.point);
         visited_points_dirs.insert((beam.point, beam.direction));
 
-        println!(
+        trace!(
             "Beam {} starts at {} going {:?}",
-            beam.id, beam.point, beam.direction
+            beam.id,
+            beam.point,
+            beam.direction
         );
         if let Some(split_beam) = beam.transform(&map) {
-            println!("Beam {} spawns Beam {}", beam.id, split_beam.id);
+            trace!("Beam {} spawns Beam {}", beam.id, split_beam.id);
             beam_q.push_back(split_beam);
         }
-        println!(
+        trace!(
             "Beam {} moves to {} going {:?}",
-            beam.id, beam.point, beam.direction
+            beam.id,
+            beam.point,
+            beam.direction
         );
 
         if beam.alive {
@@ -294,7 +170,15 @@ pub fn run() {
                 beam_q.push_back(beam);
             }
         }
+
+        visualize::record(|| {
+            Frame::new("Beams", input.clone())
+                .highlight(visited_points.iter().copied(), Color::Yellow)
+                .highlight(beam_q.iter().map(|beam| beam.point), Color::Red)
+                .counter("beams", beam_q.len())
+                .counter("energized", visited_points.len())
+        });
     }
 
-    println!("{}", visited_points.len());
+    visited_points.len()
 }
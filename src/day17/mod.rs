@@ -1,8 +1,54 @@
 // https://adventofcode.com/2023/day/17
 
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u64>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("example.txt"),
+            part1: Some("102"),
+            part2: Some("94"),
+        },
+        Example {
+            input: include_str!("example2.txt"),
+            part1: None,
+            part2: Some("71"),
+        },
+    ];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Grid::parse(17, &lines, |c| c.to_digit(10).map(u64::from))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day17>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day17>(2);
+    }
 }
@@ -1,20 +1,10 @@
-use std::cmp::Reverse;
-use std::collections::HashMap;
 use std::fmt::Formatter;
 use std::ops::Index;
 
-use itertools::Itertools;
-use priority_queue::PriorityQueue;
-
-use crate::common::get_input;
-
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+use crate::common::geometry::Direction;
+use crate::common::grid::{Grid, Pos};
+use crate::common::search;
+use crate::common::visualize::{self, Color, Frame};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Position {
@@ -56,64 +46,21 @@ impl Index<NodeVariant> for [usize; 12] {
 
 #[derive(Debug)]
 struct Graph {
-    size: usize,
-    matrix: Vec<Vec<u64>>,
+    matrix: Grid<u64>,
 }
 
 impl Graph {
-    fn new(input: &Vec<String>) -> Self {
-        let size = input.len();
-        let matrix: Vec<Vec<u64>> = input.iter().enumerate().map(|(row, row_str)| {
-            assert_eq!(size, row_str.len(), "Matrix must be square");
-            row_str.chars().enumerate().map(|(col, c)| {
-                c.to_digit(10).expect("Parsing a digit") as u64
-            }).collect_vec()
-        }).collect_vec();
-        Self { size, matrix }
-    }
-
-    fn next_position(&self, start: Position, direction: Direction) -> Option<Position> {
-        let (next_row, next_col) = self.next_point(start.row, start.col, direction)?;
-        let next_variant = self.next_variant(start.variant, direction)?;
-        Some(Position { row: next_row, col: next_col, variant: next_variant })
+    fn new(input: &Grid<u64>) -> Self {
+        Self { matrix: input.clone() }
     }
 
     fn heat_loss_at(&self, position: &Position) -> u64 {
-        self.matrix[position.row][position.col]
+        self.matrix[Pos::new(position.row, position.col)]
     }
 
     fn next_point(&self, row: usize, col: usize, direction: Direction) -> Option<(usize, usize)> {
-        use Direction::*;
-        match direction {
-            Up => {
-                if row == 0 {
-                    None
-                } else {
-                    Some((row - 1, col))
-                }
-            }
-            Right => {
-                if col == self.size - 1 {
-                    None
-                } else {
-                    Some((row, col + 1))
-                }
-            }
-            Down => {
-                if row == self.size - 1 {
-                    None
-                } else {
-                    Some((row + 1, col))
-                }
-            }
-            Left => {
-                if col == 0 {
-                    None
-                } else {
-                    Some((row, col - 1))
-                }
-            }
-        }
+        let next = self.matrix.step(Pos::new(row, col), direction)?;
+        Some((next.row, next.col))
     }
 
     fn next_variant(&self, start_variant: NodeVariant, direction: Direction) -> Option<NodeVariant> {
@@ -171,65 +118,49 @@ impl Graph {
         ((goal.0 - start_pos.row) + (goal.1 - start_pos.col)) as u64
     }
 
-    fn reconstruct_path(&self, came_from: &HashMap<Position, Position>, current: &Position) -> u64 {
-        let mut current = current;
-        let mut total = self.heat_loss_at(current);
-        while came_from.contains_key(current) {
-            // println!("{:?}-{:?}", current, self.heat_loss_at(current));
-            current = &came_from[current];
-            total += self.heat_loss_at(current);
-        }
-        // Don't include first item
-        total - self.heat_loss_at(current)
-    }
-
     fn a_star(&self, start_pos: &Position) -> u64 {
-        let mut open_pq = PriorityQueue::<Position, Reverse<u64>>::new();
-        open_pq.push(*start_pos, Reverse(0));
-
-        let mut came_from = HashMap::<Position, Position>::new();
-
-        let mut g_score = HashMap::new();
-        g_score.insert(*start_pos, 0u64);
-
-        while !open_pq.is_empty() {
-            let (current, _) = open_pq.pop().expect("Pop");
-            // println!("current={:?}", current);
-            if (current.row, current.col) == self.goal() {
-                // Found path to goal
-                return self.reconstruct_path(&came_from, &current);
-            }
-
-            for neighbor in self.get_neighbors(&current) {
-                if let Some(&tentative_g_score) = g_score.get(&current) {
-                    let tentative_g_score = tentative_g_score + self.heat_loss_at(&neighbor);
-                    if g_score.get(&neighbor).map_or(true, |g| tentative_g_score < *g) {
-                        // This is the best path to neighbor
-                        came_from.insert(neighbor, current);
-                        g_score.insert(neighbor, tentative_g_score);
-                        let h = self.heuristic(&neighbor);
-                        let neighbor_f_score = tentative_g_score + h;
-                        open_pq.push(neighbor, Reverse(neighbor_f_score));
-                    }
+        let mut explored = Vec::new();
+        let result = search::astar(
+            *start_pos,
+            |current| {
+                if visualize::is_recording() {
+                    explored.push(Pos::new(current.row, current.col));
+                    self.record_search("Exploring", &explored, &[*current], None);
                 }
+                self.get_neighbors(current).into_iter().map(|neighbor| (neighbor, self.heat_loss_at(&neighbor)))
+            },
+            |position| self.heuristic(position),
+            |current| (current.row, current.col) == self.goal(),
+        );
+        let path = result.path.expect("Failed to find a path");
+        self.record_search("Cheapest path", &explored, &path.nodes, Some(path.cost));
+        path.cost
+    }
+
+    fn record_search(&self, title: &str, explored: &[Pos], path: &[Position], heat_loss: Option<u64>) {
+        visualize::record(|| {
+            let frame = Frame::from_grid(title, &self.matrix, |&loss| char::from_digit(loss as u32, 10).unwrap_or('?'))
+                .highlight(explored.iter().copied(), Color::Blue)
+                .highlight(path.iter().map(|position| Pos::new(position.row, position.col)), Color::Yellow)
+                .counter("explored", explored.len());
+            match heat_loss {
+                Some(heat_loss) => frame.counter("heat loss", heat_loss),
+                None => frame,
             }
-        }
-        panic!("Failed to find a path");
+        });
     }
 
     fn goal(&self) -> (usize, usize) {
-        (self.size - 1, self.size - 1)
+        (self.matrix.height() - 1, self.matrix.width() - 1)
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day17/input1.txt");
-    let graph = Box::new(Graph::new(&input));
+pub fn solve(input: &Grid<u64>) -> u64 {
+    let graph = Box::new(Graph::new(input));
     let start_pos = Position {
         row: 0,
         col: 0,
         variant: NodeVariant::Init,
     };
-    let ans = graph.a_star(&start_pos);
-    println!("{ans}");
+    graph.a_star(&start_pos)
 }
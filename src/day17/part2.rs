@@ -1,25 +1,16 @@
-use std::cmp::Reverse;
-use std::collections::HashMap;
 use std::fmt::Formatter;
 
-use itertools::Itertools;
-use priority_queue::PriorityQueue;
+use log::trace;
 
-use crate::common::get_input;
-use crate::day17::part2::Direction::{Down, Left, Right, Up};
+use crate::common::geometry::Direction::{self, Down, Left, Right, Up};
+use crate::common::grid::{Grid, Pos};
+use crate::common::search;
+use crate::common::visualize::{self, Color, Frame};
 use crate::day17::part2::NodeVariant::{D, Init, L, R, U};
 
 const MIN_DIST_BEFORE_TURN: u8 = 4;
 const MAX_STRAIGHT_DIST: u8 = 10;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Position {
     row: usize,
@@ -99,7 +90,7 @@ impl NodeVariant {
             (U(_), Down) | (R(_), Left) | (D(_), Up) | (L(_), Right) => None,
 
             // Continuing straight
-            (U(dist), Up) | (R(dist), Right) | (D(dist), Down) | (L(dist), Left) => self.try_continue_straight(),
+            (U(_), Up) | (R(_), Right) | (D(_), Down) | (L(_), Left) => self.try_continue_straight(),
 
             // 90 degrees
             _ => self.try_turn(direction)
@@ -117,66 +108,21 @@ impl NodeVariant {
 
 #[derive(Debug)]
 struct Graph {
-    height: usize,
-    width: usize,
-    matrix: Vec<Vec<u64>>,
+    matrix: Grid<u64>,
 }
 
 impl Graph {
-    fn new(input: &Vec<String>) -> Self {
-        let height = input.len();
-        let width = input[0].len();
-        let matrix: Vec<Vec<u64>> = input.iter().enumerate().map(|(row, row_str)| {
-            assert_eq!(width, row_str.len(), "Matrix must be rectangular");
-            row_str.chars().enumerate().map(|(col, c)| {
-                c.to_digit(10).expect("Parsing a digit") as u64
-            }).collect_vec()
-        }).collect_vec();
-        Self { height, width, matrix }
-    }
-
-    fn next_position(&self, start: Position, direction: Direction) -> Option<Position> {
-        let (next_row, next_col) = self.next_point(start.row, start.col, direction)?;
-        let next_variant = self.next_variant(start.variant, direction)?;
-        Some(Position { row: next_row, col: next_col, variant: next_variant })
+    fn new(input: &Grid<u64>) -> Self {
+        Self { matrix: input.clone() }
     }
 
     fn heat_loss_at(&self, position: &Position) -> u64 {
-        self.matrix[position.row][position.col]
+        self.matrix[Pos::new(position.row, position.col)]
     }
 
     fn next_point(&self, row: usize, col: usize, direction: Direction) -> Option<(usize, usize)> {
-        use Direction::*;
-        match direction {
-            Up => {
-                if row == 0 {
-                    None
-                } else {
-                    Some((row - 1, col))
-                }
-            }
-            Right => {
-                if col == self.width - 1 {
-                    None
-                } else {
-                    Some((row, col + 1))
-                }
-            }
-            Down => {
-                if row == self.height - 1 {
-                    None
-                } else {
-                    Some((row + 1, col))
-                }
-            }
-            Left => {
-                if col == 0 {
-                    None
-                } else {
-                    Some((row, col - 1))
-                }
-            }
-        }
+        let next = self.matrix.step(Pos::new(row, col), direction)?;
+        Some((next.row, next.col))
     }
 
     fn next_variant(&self, start_variant: NodeVariant, direction: Direction) -> Option<NodeVariant> {
@@ -194,7 +140,7 @@ impl Graph {
                 }
             }
         }
-        // println!("from {:?}, neighbors are {:?}", start_position, neighbors);
+        trace!("from {:?}, neighbors are {:?}", start_position, neighbors);
         neighbors
     }
 
@@ -204,68 +150,52 @@ impl Graph {
         ((goal.0 - start_pos.row) + (goal.1 - start_pos.col)) as u64
     }
 
-    fn reconstruct_path(&self, came_from: &HashMap<Position, Position>, current: &Position) -> u64 {
-        let mut current = current;
-        let mut total = self.heat_loss_at(current);
-        while came_from.contains_key(current) {
-            // println!("{:?}-{:?}", current, self.heat_loss_at(current));
-            current = &came_from[current];
-            total += self.heat_loss_at(current);
-        }
-        // Don't include first item
-        total - self.heat_loss_at(current)
-    }
-
     fn a_star(&self, start_pos: &Position) -> u64 {
-        let mut open_pq = PriorityQueue::<Position, Reverse<u64>>::new();
-        open_pq.push(*start_pos, Reverse(0));
-
-        let mut came_from = HashMap::<Position, Position>::new();
-
-        let mut g_score = HashMap::new();
-        g_score.insert(*start_pos, 0u64);
-
-        while !open_pq.is_empty() {
-            let (current, _) = open_pq.pop().expect("Pop");
-            // println!("current={:?}", current);
-            if (current.row, current.col) == self.goal() {
-                // Found path to goal
-                // But path must be able to turn in order to stop
-                if current.variant.can_turn() {
-                    return self.reconstruct_path(&came_from, &current);
-                }
-            }
-
-            for neighbor in self.get_neighbors(&current) {
-                if let Some(&tentative_g_score) = g_score.get(&current) {
-                    let tentative_g_score = tentative_g_score + self.heat_loss_at(&neighbor);
-                    if g_score.get(&neighbor).map_or(true, |g| tentative_g_score < *g) {
-                        // This is the best path to neighbor
-                        came_from.insert(neighbor, current);
-                        g_score.insert(neighbor, tentative_g_score);
-                        let h = self.heuristic(&neighbor);
-                        let neighbor_f_score = tentative_g_score + h;
-                        open_pq.push(neighbor, Reverse(neighbor_f_score));
-                    }
+        let mut explored = Vec::new();
+        let result = search::astar(
+            *start_pos,
+            |current| {
+                if visualize::is_recording() {
+                    explored.push(Pos::new(current.row, current.col));
+                    self.record_search("Exploring", &explored, &[*current], None);
                 }
+                self.get_neighbors(current).into_iter().map(|neighbor| (neighbor, self.heat_loss_at(&neighbor)))
+            },
+            |position| self.heuristic(position),
+            |current| {
+                // The path must be able to turn in order to stop
+                (current.row, current.col) == self.goal() && current.variant.can_turn()
+            },
+        );
+        let path = result.path.expect("Failed to find a path");
+        self.record_search("Cheapest path", &explored, &path.nodes, Some(path.cost));
+        path.cost
+    }
+
+    fn record_search(&self, title: &str, explored: &[Pos], path: &[Position], heat_loss: Option<u64>) {
+        visualize::record(|| {
+            let frame = Frame::from_grid(title, &self.matrix, |&loss| char::from_digit(loss as u32, 10).unwrap_or('?'))
+                .highlight(explored.iter().copied(), Color::Blue)
+                .highlight(path.iter().map(|position| Pos::new(position.row, position.col)), Color::Yellow)
+                .counter("explored", explored.len());
+            match heat_loss {
+                Some(heat_loss) => frame.counter("heat loss", heat_loss),
+                None => frame,
             }
-        }
-        panic!("Failed to find a path");
+        });
     }
 
     fn goal(&self) -> (usize, usize) {
-        (self.height - 1, self.width - 1)
+        (self.matrix.height() - 1, self.matrix.width() - 1)
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day17/input1.txt");
-    let graph = Box::new(Graph::new(&input));
+pub fn solve(input: &Grid<u64>) -> u64 {
+    let graph = Box::new(Graph::new(input));
     let start_pos = Position {
         row: 0,
         col: 0,
         variant: Init,
     };
-    let ans = graph.a_star(&start_pos);
-    println!("{ans}");
+    graph.a_star(&start_pos)
 }
@@ -1,131 +1,23 @@
 use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
 
-use bmp::{px, Image, Pixel};
-use itertools::Itertools;
 use queues::*;
 
-use crate::common::get_input;
+use crate::common::geometry::{Direction, Point};
+use crate::common::grid::{Grid, Pos};
+use crate::common::image::Rgb;
+use crate::common::visualize::{self, Color, Frame};
 
-const IN_FILE: &str = "src/day18/input1.txt";
-const OUT_FILE: &str = "src/day18/output1.bmp";
+use super::DigStep;
 
-const DEFAULT_COLOR: &str = "#000000";
-const FILL_COLOR: &str = "#ffffff";
+const DEFAULT_COLOR: Rgb = Rgb::BLACK;
+const FILL_COLOR: Rgb = Rgb::WHITE;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    U,
-    R,
-    D,
-    L,
-}
-
-impl Direction {
-    fn new(input: &str) -> Self {
-        match input {
-            "U" => Direction::U,
-            "R" => Direction::R,
-            "D" => Direction::D,
-            "L" => Direction::L,
-            _ => panic!("Bad direction {input}"),
-        }
-    }
-
-    fn turn_90_cw(&self) -> Self {
-        use Direction::*;
-        match self {
-            U => R,
-            R => D,
-            D => L,
-            L => U,
-        }
-    }
-
-    fn turn_180(&self) -> Self {
-        use Direction::*;
-        match self {
-            U => D,
-            R => L,
-            D => U,
-            L => R,
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-struct Color {
-    r: u8,
-    g: u8,
-    b: u8,
-}
-
-impl Color {
-    fn new(input: &str) -> Self {
-        let input = input.strip_prefix('#').unwrap_or(input);
-        let decoded_string = hex::decode(input).unwrap();
-        assert_eq!(3, decoded_string.len(), "Expected 3 hexes in RGB code");
-        Self {
-            r: decoded_string[0],
-            g: decoded_string[1],
-            b: decoded_string[2],
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
-struct Coord {
-    x: i32,
-    y: i32,
-}
-
-impl Coord {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    fn next(&self, direction: Direction) -> Self {
-        match direction {
-            Direction::U => Self::new(self.x, self.y - 1),
-            Direction::R => Self::new(self.x + 1, self.y),
-            Direction::D => Self::new(self.x, self.y + 1),
-            Direction::L => Self::new(self.x - 1, self.y),
-        }
-    }
-
-    fn direction_to_neighbor(&self, other: Coord) -> Direction {
-        match (other.x - self.x, other.y - self.y) {
-            (0, -1) => Direction::U,
-            (1, 0) => Direction::R,
-            (0, 1) => Direction::D,
-            (-1, 0) => Direction::L,
-            _ => panic!("{:?} is not a neighbor of {:?}", other, self),
-        }
-    }
-}
-
-struct Bitmap {
-    width: u32,
-    height: u32,
-    pixels: Vec<Option<Color>>,
-}
-
-impl Bitmap {
-    fn render(&self, output_file: &str) {
-        let mut img = Image::new(self.width, self.height);
-        for (x, y) in img.coordinates() {
-            if let Some(color) = self.pixels[x as usize + (y * self.width) as usize] {
-                img.set_pixel(x, y, px!(color.r, color.g, color.b));
-            }
-        }
-        let _ = img.save(output_file);
-    }
-}
+type Coord = Point<i32>;
 
 #[derive(Debug)]
 struct VectorPoint {
     coord: Coord,
-    color: Color,
+    color: Rgb,
     is_border: bool,
 }
 
@@ -144,7 +36,7 @@ impl VectorImage {
             bottom_left: start_coord,
             top_right: start_coord,
         };
-        new_svg.add_border_point(start_coord, Color::new(DEFAULT_COLOR));
+        new_svg.add_border_point(start_coord, DEFAULT_COLOR);
         new_svg
     }
 
@@ -152,7 +44,7 @@ impl VectorImage {
         Coord::new(0, 0)
     }
 
-    fn add_border_point(&mut self, coord: Coord, color: Color) {
+    fn add_border_point(&mut self, coord: Coord, color: Rgb) {
         // Expand bounding box
         self.bottom_left.x = std::cmp::min(self.bottom_left.x, coord.x);
         self.bottom_left.y = std::cmp::max(self.bottom_left.y, coord.y);
@@ -168,7 +60,7 @@ impl VectorImage {
         self.points.insert(coord, point);
     }
 
-    fn add_interior_point(&mut self, coord: Coord, color: Color) {
+    fn add_interior_point(&mut self, coord: Coord, color: Rgb) {
         let point = VectorPoint {
             coord,
             color,
@@ -178,10 +70,9 @@ impl VectorImage {
     }
 
     fn get_edge_neighbors(&self, coord: Coord) -> Vec<Coord> {
-        use Direction::*;
         let mut neighbors = Vec::new();
-        for direction in [U, R, D, L] {
-            if let Some(neighbor) = self.points.get(&coord.next(direction)) {
+        for neighbor_coord in coord.neighbors4() {
+            if let Some(neighbor) = self.points.get(&neighbor_coord) {
                 neighbors.push(neighbor.coord);
             }
         }
@@ -190,11 +81,9 @@ impl VectorImage {
     }
 
     fn get_non_edge_neighbors(&self, coord: Coord) -> Vec<Coord> {
-        use Direction::*;
         let mut neighbors = Vec::new();
-        for direction in [U, R, D, L] {
-            let neighbor_coord = coord.next(direction);
-            if self.points.get(&neighbor_coord).is_none() {
+        for neighbor_coord in coord.neighbors4() {
+            if !self.points.contains_key(&neighbor_coord) {
                 neighbors.push(neighbor_coord);
             }
         }
@@ -238,8 +127,8 @@ impl VectorImage {
         // Advance until we're on an edge point (not a corner)
         let mut coord = self.points.get(&Self::start_coord()).unwrap().coord;
         let mut neighbors = self.get_edge_neighbors(coord);
-        while coord.direction_to_neighbor(neighbors[0])
-            != coord.direction_to_neighbor(neighbors[1]).turn_180()
+        while coord.direction_to(neighbors[0])
+            != coord.direction_to(neighbors[1]).map(Direction::reverse)
         {
             // This is a corner
             coord = neighbors[0];
@@ -249,81 +138,50 @@ impl VectorImage {
         // 2 neighbors - this is on an edge
         debug_assert_eq!(2, neighbors.len());
         let neighbor = neighbors[0];
-        let edge_dir = coord.direction_to_neighbor(neighbor);
+        let edge_dir = coord
+            .direction_to(neighbor)
+            .expect("Edge neighbors are adjacent");
 
         // Try filling in this direction
         // If we hit the edge of the bounding box, try the other potential direction
-        let non_edge_dir = edge_dir.turn_90_cw();
-        let non_edge_coord = coord.next(non_edge_dir);
+        let non_edge_dir = edge_dir.turn_cw();
+        let non_edge_coord = coord.step(non_edge_dir);
         self.try_fill(non_edge_coord).unwrap_or_else(|| {
             // Try again with other non-edge coord
-            let other_non_edge_coord = coord.next(non_edge_dir.turn_180());
+            let other_non_edge_coord = coord.step(non_edge_dir.reverse());
             self.try_fill(other_non_edge_coord)
                 .expect("Failed to fill in either direction")
         })
     }
 
-    fn fill_polygon(&mut self, fill_color: Color) {
+    fn fill_polygon(&mut self, fill_color: Rgb) {
         let fill_coords = self.get_fill_coords();
         for f in fill_coords {
             self.add_interior_point(f, fill_color);
         }
     }
 
-    fn rasterize(&self) -> Bitmap {
-        assert!(self.top_right.x > self.bottom_left.x);
-        assert!(self.bottom_left.y > self.top_right.y);
+    /// Draw the trench, with its border in the colors it was painted with.
+    fn to_frame(&self) -> Frame {
         let width = (self.top_right.x - self.bottom_left.x + 1) as usize;
         let height = (self.bottom_left.y - self.top_right.y + 1) as usize;
-
-        let mut bitmap = vec![None; width * height];
-
-        // Draw border
-        for (coord, vector_point) in self.points.iter() {
-            assert!(coord.x >= self.bottom_left.x);
-            assert!(coord.x <= self.top_right.x);
-            assert!(coord.y <= self.bottom_left.y);
-            assert!(coord.y >= self.top_right.y);
-
-            let rasterized_x = (coord.x - self.bottom_left.x) as usize;
-            let rasterized_y = (coord.y - self.top_right.y) as usize;
-            let bitmap_index = rasterized_y * width + rasterized_x;
-            bitmap[bitmap_index] = Some(vector_point.color);
-        }
-
-        Bitmap {
-            pixels: bitmap,
-            width: width as u32,
-            height: height as u32,
-        }
-    }
-}
-
-#[derive(Debug)]
-struct LineSegment {
-    direction: Direction,
-    distance: i32,
-    color: Color,
-}
-
-impl LineSegment {
-    fn new(input: &str) -> Self {
-        let tokens = input.split_ascii_whitespace().collect_vec();
-        let direction = Direction::new(tokens[0]);
-        let distance = tokens[1].parse::<i32>().unwrap();
-
-        let color = {
-            let color_str = tokens[2];
-            let color_str = color_str.strip_prefix('(').unwrap_or(color_str);
-            let color_str = color_str.strip_suffix(')').unwrap_or(color_str);
-            Color::new(color_str)
+        let pos = |coord: &Coord| {
+            Pos::new(
+                (coord.y - self.top_right.y) as usize,
+                (coord.x - self.bottom_left.x) as usize,
+            )
         };
 
-        Self {
-            direction,
-            distance,
-            color,
+        let mut grid = Grid::new(width, height, '.');
+        for coord in self.points.keys() {
+            grid[pos(coord)] = '#';
+        }
+        let mut frame = Frame::new("Lagoon", grid).counter("cubic meters", self.points.len());
+        for (coord, point) in self.points.iter().filter(|(_, p)| p.is_border) {
+            let Rgb { r, g, b } = point.color;
+            frame = frame.highlight([pos(coord)], Color::Rgb(r, g, b));
         }
+        frame
     }
 }
 
@@ -338,34 +196,25 @@ impl VectorPainter {
         }
     }
 
-    fn paint(&mut self, step: &LineSegment, svg: &mut VectorImage) {
-        for i in 0..step.distance {
-            let next_coord = self.location.next(step.direction);
+    fn paint(&mut self, step: &DigStep, svg: &mut VectorImage) {
+        for _ in 0..step.distance {
+            let next_coord = self.location.step(step.direction);
             svg.add_border_point(next_coord, step.color);
             self.location = next_coord;
         }
     }
 }
 
-pub fn run() {
-    let input = get_input(IN_FILE);
-
+pub fn solve(input: &[DigStep]) -> usize {
     // Assume data draws a polygon that does not intersect with itself, and
     // that no two edge segments are touching.
     let mut svg = VectorImage::new();
     let mut painter = VectorPainter::new(VectorImage::start_coord());
-    input
-        .iter()
-        .map(|line| line.as_str())
-        .map(LineSegment::new)
-        .for_each(|seg| {
-            painter.paint(&seg, &mut svg);
-        });
-    svg.fill_polygon(Color::new(FILL_COLOR));
-
-    let bitmap = svg.rasterize();
-    bitmap.render(OUT_FILE);
+    for step in input {
+        painter.paint(step, &mut svg);
+    }
+    svg.fill_polygon(FILL_COLOR);
+    visualize::record(|| svg.to_frame());
 
-    let area = svg.points.len();
-    println!("Area: {area}");
+    svg.points.len()
 }
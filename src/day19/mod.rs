@@ -1,8 +1,353 @@
 // https://adventofcode.com/2023/day/19
 
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// The workflow every part starts in.
+pub const START: &str = "in";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Attribute {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// Condition always passes
+    Always,
+
+    /// Condition passes if attribute > threshold
+    AttrGreaterThan {
+        attribute: Attribute,
+        threshold: i32,
+    },
+
+    /// Condition passes if attribute < threshold
+    AttrLessThan {
+        attribute: Attribute,
+        threshold: i32,
+    },
+}
+
+/// Next workflow, or a terminal destination
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    /// Terminal - either accept or reject the part
+    Terminal { accept: bool },
+
+    /// Go to another workflow by name
+    Next { workflow_name: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// If condition passes, go to destination
+    pub condition: Condition,
+
+    pub destination: Destination,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow {
+    pub name: String,
+    /// The last rule always passes, so every part leaves the workflow.
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub x: i32,
+    pub m: i32,
+    pub a: i32,
+    pub s: i32,
+}
+
+impl Part {
+    pub fn get_attribute(&self, attribute: Attribute) -> i32 {
+        match attribute {
+            Attribute::X => self.x,
+            Attribute::M => self.m,
+            Attribute::A => self.a,
+            Attribute::S => self.s,
+        }
+    }
+
+    pub fn rating(&self) -> i32 {
+        self.x + self.m + self.a + self.s
+    }
+
+    pub fn meets_condition(&self, condition: &Condition) -> bool {
+        match *condition {
+            Condition::Always => true,
+            Condition::AttrGreaterThan {
+                attribute,
+                threshold,
+            } => self.get_attribute(attribute) > threshold,
+            Condition::AttrLessThan {
+                attribute,
+                threshold,
+            } => self.get_attribute(attribute) < threshold,
+        }
+    }
+}
+
+/// The workflows by name, every one of them reachable from [`START`] only through workflows that
+/// exist, and the parts to sort.
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+impl System {
+    pub fn workflow(&self, name: &str) -> &Workflow {
+        &self.workflows[name]
+    }
+}
+
+fn parse_destination(line: Line, text: &str) -> InputResult<Destination> {
+    match text {
+        "A" => Ok(Destination::Terminal { accept: true }),
+        "R" => Ok(Destination::Terminal { accept: false }),
+        _ if !text.is_empty() && text.bytes().all(|b| b.is_ascii_lowercase()) => {
+            Ok(Destination::Next {
+                workflow_name: text.to_string(),
+            })
+        }
+        _ => Err(line.error(text, "expected `A`, `R` or a workflow name")),
+    }
+}
+
+fn parse_rule(line: Line, text: &str) -> InputResult<Rule> {
+    let Some((condition, destination)) = text.split_once(':') else {
+        return Ok(Rule {
+            condition: Condition::Always,
+            destination: parse_destination(line, text)?,
+        });
+    };
+    let attribute = match condition.get(..1) {
+        Some("x") => Attribute::X,
+        Some("m") => Attribute::M,
+        Some("a") => Attribute::A,
+        Some("s") => Attribute::S,
+        _ => return Err(line.error(condition, "expected `x`, `m`, `a` or `s`")),
+    };
+    let greater = match condition.get(1..2) {
+        Some(">") => true,
+        Some("<") => false,
+        _ => return Err(line.error(&condition[1..], "expected `<` or `>`")),
+    };
+    let threshold = line.token(&condition[2..])?;
+    let condition = if greater {
+        Condition::AttrGreaterThan {
+            attribute,
+            threshold,
+        }
+    } else {
+        Condition::AttrLessThan {
+            attribute,
+            threshold,
+        }
+    };
+    Ok(Rule {
+        condition,
+        destination: parse_destination(line, destination)?,
+    })
+}
+
+fn parse_workflow(line: Line) -> InputResult<Workflow> {
+    static WORKFLOW_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([a-z]+)\{(.*)\}$").unwrap());
+    let caps = line.captures(&WORKFLOW_REGEX)?;
+    let rules_text = caps.get(2).unwrap().as_str();
+    let rules = rules_text
+        .split(',')
+        .map(|text| parse_rule(line, text))
+        .collect::<InputResult<Vec<_>>>()?;
+    if rules.last().map(|rule| rule.condition) != Some(Condition::Always) {
+        return Err(line.error(rules_text, "expected the last rule to have no condition"));
+    }
+    Ok(Workflow {
+        name: caps[1].to_string(),
+        rules,
+    })
+}
+
+fn parse_part(line: Line) -> InputResult<Part> {
+    static PART_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap());
+    let caps = line.captures(&PART_REGEX)?;
+    let rating = |i| line.token(caps.get(i).unwrap().as_str());
+    Ok(Part {
+        x: rating(1)?,
+        m: rating(2)?,
+        a: rating(3)?,
+        s: rating(4)?,
+    })
+}
+
+/// Check that every workflow a part can be sent to exists.
+fn check_workflows(workflows: &HashMap<String, Workflow>) -> InputResult<()> {
+    if !workflows.contains_key(START) {
+        return Err(InputError::malformed(
+            19,
+            format!("no `{START}` workflow to start in"),
+        ));
+    }
+    for workflow in workflows.values() {
+        for rule in &workflow.rules {
+            if let Destination::Next { workflow_name } = &rule.destination {
+                if !workflows.contains_key(workflow_name) {
+                    return Err(InputError::malformed(
+                        19,
+                        format!(
+                            "workflow `{}` sends parts to `{workflow_name}`, which is not a workflow",
+                            workflow.name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("19114"),
+        part2: Some("167409079868000"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let mut blocks = parse::blocks(&lines);
+        let mut workflows = HashMap::new();
+        if let Some((start, block)) = blocks.next() {
+            for (i, text) in block.iter().enumerate() {
+                let line = Line::new(19, start + i, text);
+                let workflow = parse_workflow(line)?;
+                if workflows.contains_key(&workflow.name) {
+                    return Err(line.error(
+                        text,
+                        format!("workflow `{}` is defined twice", workflow.name),
+                    ));
+                }
+                workflows.insert(workflow.name.clone(), workflow);
+            }
+        }
+        check_workflows(&workflows)?;
+
+        let parts = match blocks.next() {
+            Some((start, block)) => block
+                .iter()
+                .enumerate()
+                .map(|(i, text)| parse_part(Line::new(19, start + i, text)))
+                .collect::<InputResult<_>>()?,
+            None => Vec::new(),
+        };
+        if let Some((start, _)) = blocks.next() {
+            return Err(InputError::parse(19, start, 0, "expected no more parts"));
+        }
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, get_input_from_str};
+
+    #[test]
+    fn test_parse() {
+        let system = Day19::parse(get_input_from_str(Day19::EXAMPLES[0].input)).unwrap();
+        assert_eq!(system.workflows.len(), 11);
+        assert_eq!(system.parts.len(), 5);
+        assert_eq!(
+            system.workflow("pv").rules,
+            [
+                Rule {
+                    condition: Condition::AttrGreaterThan {
+                        attribute: Attribute::A,
+                        threshold: 1716
+                    },
+                    destination: Destination::Terminal { accept: false }
+                },
+                Rule {
+                    condition: Condition::Always,
+                    destination: Destination::Terminal { accept: true }
+                }
+            ]
+        );
+        assert_eq!(
+            system.parts[0],
+            Part {
+                x: 787,
+                m: 2655,
+                a: 1222,
+                s: 2876
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (input, expected_line, expected_column) in [
+            ("garbage", 1, 1),
+            ("in{x>1:A}", 1, 4),
+            ("in{q>1:A,R}", 1, 4),
+            ("in{x=1:A,R}", 1, 5),
+            ("in{x>y:A,R}", 1, 6),
+            ("in{x>1:A,R7}", 1, 10),
+            ("in{A}\nin{R}", 2, 1),
+            ("in{A}\n\n{x=1,m=2,a=3}", 3, 1),
+            ("in{A}\n\n{x=1,m=2,a=3,s=4}\n\n{x=1,m=2,a=3,s=4}", 5, 1),
+        ] {
+            match Day19::parse(get_input_from_str(input)) {
+                Err(InputError::Parse { line, column, .. }) => {
+                    assert_eq!((line, column), (expected_line, expected_column), "{input}")
+                }
+                other => panic!("unexpected result for {input}: {:?}", other.err()),
+            }
+        }
+        for input in ["px{A}", "in{x>1:px,R}", ""] {
+            assert!(
+                matches!(
+                    Day19::parse(get_input_from_str(input)),
+                    Err(InputError::Malformed { .. })
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day19>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day19>(2);
+    }
 }
@@ -0,0 +1,290 @@
+//! Which games many different bags make possible, and the smallest bags that make them all
+//! possible.
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use super::{CubeSet, Game};
+
+/// The games one bag makes possible.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Feasibility {
+    pub bag: CubeSet,
+    /// The IDs of the possible games, in input order.
+    pub ids: Vec<u32>,
+}
+
+impl Feasibility {
+    pub fn id_sum(&self) -> u32 {
+        self.ids.iter().sum()
+    }
+}
+
+/// Every bag with a count in the given range for each color, and no cubes of other colors.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct BagRange {
+    ranges: Vec<(String, RangeInclusive<u32>)>,
+}
+
+impl BagRange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Try every count in `range` for `color`, instead of any range given for it before.
+    pub fn with(mut self, color: impl Into<String>, range: RangeInclusive<u32>) -> Self {
+        let color = color.into();
+        match self.ranges.iter_mut().find(|(c, _)| *c == color) {
+            Some((_, old)) => *old = range,
+            None => self.ranges.push((color, range)),
+        }
+        self
+    }
+
+    /// The bags one at a time, with the last color's count changing fastest.
+    pub fn bags(&self) -> Bags<'_> {
+        let counts = self
+            .ranges
+            .iter()
+            .all(|(_, range)| !range.is_empty())
+            .then(|| {
+                self.ranges
+                    .iter()
+                    .map(|(_, range)| *range.start())
+                    .collect()
+            });
+        Bags {
+            ranges: &self.ranges,
+            counts,
+        }
+    }
+
+    /// The bags in this range that make all `games` possible and don't contain another one that
+    /// does, without trying them all: the range holds at most one, the [`smallest_bag`] with each
+    /// count raised to the bottom of its range.
+    pub fn frontier(&self, games: &[Game]) -> Option<CubeSet> {
+        let smallest = smallest_bag(games);
+        if smallest
+            .colors()
+            .any(|color| !self.ranges.iter().any(|(c, _)| c == color))
+        {
+            return None;
+        }
+        self.ranges
+            .iter()
+            .try_fold(CubeSet::new(), |bag, (color, range)| {
+                let n = smallest.count(color).max(*range.start());
+                range.contains(&n).then(|| bag.with(color.clone(), n))
+            })
+    }
+}
+
+/// The bags of a [`BagRange`], built as they are needed.
+#[derive(Clone, Debug)]
+pub struct Bags<'a> {
+    ranges: &'a [(String, RangeInclusive<u32>)],
+    /// The counts of the next bag, or `None` once every bag has been seen.
+    counts: Option<Vec<u32>>,
+}
+
+impl Iterator for Bags<'_> {
+    type Item = CubeSet;
+
+    fn next(&mut self) -> Option<CubeSet> {
+        let counts = self.counts.as_mut()?;
+        let bag = self
+            .ranges
+            .iter()
+            .zip(counts.iter())
+            .fold(CubeSet::new(), |bag, ((color, _), &n)| {
+                bag.with(color.clone(), n)
+            });
+
+        // Count up like an odometer, carrying into the color before when one wraps around.
+        let mut carry = true;
+        for ((_, range), n) in self.ranges.iter().zip(counts.iter_mut()).rev() {
+            if *n < *range.end() {
+                *n += 1;
+                carry = false;
+                break;
+            }
+            *n = *range.start();
+        }
+        if carry {
+            self.counts = None;
+        }
+        Some(bag)
+    }
+}
+
+pub fn feasibility(games: &[Game], bag: &CubeSet) -> Feasibility {
+    Feasibility {
+        bag: bag.clone(),
+        ids: games
+            .iter()
+            .filter(|game| game.is_possible(bag))
+            .map(|game| game.id)
+            .collect(),
+    }
+}
+
+/// The feasibility of each of `bags`, in order.
+pub fn feasibility_of_all(
+    games: &[Game],
+    bags: impl IntoIterator<Item = CubeSet>,
+) -> Vec<Feasibility> {
+    bags.into_iter()
+        .map(|bag| feasibility(games, &bag))
+        .collect()
+}
+
+/// The bag every bag that makes all `games` possible contains.
+pub fn smallest_bag(games: &[Game]) -> CubeSet {
+    games
+        .iter()
+        .fold(CubeSet::new(), |bag, game| bag.union(&game.minimum_bag()))
+}
+
+/// The bags among `bags` that make all `games` possible and don't contain another one that does,
+/// in the order they first come. Over every possible bag this is just [`smallest_bag`], but a
+/// limited choice of bags can leave several that trade one color for another.
+///
+/// A bag can only contain bags with fewer cubes, so checking them from the fewest cubes up, each
+/// only needs comparing with the frontier found so far rather than with every other bag.
+pub fn pareto_frontier(games: &[Game], bags: impl IntoIterator<Item = CubeSet>) -> Vec<CubeSet> {
+    let smallest = smallest_bag(games);
+    let mut seen = HashSet::new();
+    let mut feasible = bags
+        .into_iter()
+        .filter(|bag| bag.contains(&smallest) && seen.insert(bag.clone()))
+        .enumerate()
+        .collect::<Vec<_>>();
+    feasible.sort_by_cached_key(|(i, bag)| (bag.total(), *i));
+
+    let mut frontier: Vec<(usize, CubeSet)> = Vec::new();
+    for (i, bag) in feasible {
+        if !frontier.iter().any(|(_, other)| bag.contains(other)) {
+            frontier.push((i, bag));
+        }
+    }
+    frontier.sort_unstable_by_key(|&(i, _)| i);
+    frontier.into_iter().map(|(_, bag)| bag).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{get_input_from_str, Solution};
+    use crate::day2::Day2;
+
+    fn games() -> Vec<Game> {
+        Day2::parse(get_input_from_str(Day2::EXAMPLES[0].input)).unwrap()
+    }
+
+    fn rgb(red: u32, green: u32, blue: u32) -> CubeSet {
+        CubeSet::new()
+            .with("red", red)
+            .with("green", green)
+            .with("blue", blue)
+    }
+
+    #[test]
+    fn test_feasibility() {
+        let games = games();
+        let results = feasibility_of_all(&games, [rgb(12, 13, 14), rgb(20, 13, 6), rgb(0, 0, 0)]);
+        assert_eq!(results[0].ids, [1, 2, 5]);
+        assert_eq!(results[0].id_sum(), 8);
+        assert_eq!(results[1].ids, [1, 2, 3, 5]);
+        assert!(results[2].ids.is_empty());
+
+        let range = BagRange::new()
+            .with("red", 10..=20)
+            .with("green", 13..=13)
+            .with("blue", 5..=6);
+        assert_eq!(range.bags().count(), 22);
+        let best = feasibility_of_all(&games, range.bags())
+            .into_iter()
+            .max_by_key(|f| (f.id_sum(), std::cmp::Reverse(f.bag.power(&["red", "blue"]))))
+            .unwrap();
+        assert_eq!(best.bag, rgb(20, 13, 6));
+        assert_eq!(BagRange::new().bags().collect::<Vec<_>>(), [CubeSet::new()]);
+        assert_eq!(
+            BagRange::new()
+                .with("red", RangeInclusive::new(2, 1))
+                .bags()
+                .count(),
+            0
+        );
+        assert_eq!(
+            BagRange::new()
+                .with("red", 0..=1)
+                .with("blue", 1..=2)
+                .with("red", 3..=3)
+                .bags()
+                .collect::<Vec<_>>(),
+            [
+                CubeSet::new().with("red", 3).with("blue", 1),
+                CubeSet::new().with("red", 3).with("blue", 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pareto_frontier() {
+        let games = games();
+        assert_eq!(smallest_bag(&games), rgb(20, 13, 15));
+        let range = BagRange::new()
+            .with("red", 19..=21)
+            .with("green", 13..=14)
+            .with("blue", 15..=15);
+        assert_eq!(pareto_frontier(&games, range.bags()), [rgb(20, 13, 15)]);
+        assert_eq!(range.frontier(&games), Some(rgb(20, 13, 15)));
+        let bags = [
+            rgb(30, 13, 15).with("mauve", 1),
+            rgb(20, 20, 15),
+            rgb(25, 13, 20),
+            rgb(30, 20, 20),
+            rgb(20, 12, 99),
+            rgb(20, 20, 15),
+        ];
+        assert_eq!(
+            pareto_frontier(&games, bags.clone()),
+            [bags[0].clone(), bags[1].clone(), bags[2].clone()]
+        );
+    }
+
+    #[test]
+    fn test_range_frontier() {
+        let games = games();
+        for range in [
+            BagRange::new()
+                .with("red", 21..=23)
+                .with("green", 0..=14)
+                .with("blue", 10..=16),
+            BagRange::new()
+                .with("red", 0..=30)
+                .with("green", 0..=12)
+                .with("blue", 15..=15),
+            BagRange::new().with("red", 20..=20).with("green", 13..=13),
+            BagRange::new()
+                .with("blue", 15..=16)
+                .with("mauve", 0..=1)
+                .with("red", 20..=20)
+                .with("green", 13..=13),
+        ] {
+            let frontier = pareto_frontier(&games, range.bags());
+            assert_eq!(
+                range.frontier(&games).into_iter().collect::<Vec<_>>(),
+                frontier
+            );
+        }
+        assert_eq!(
+            BagRange::new()
+                .with("red", 21..=23)
+                .with("green", 0..=14)
+                .with("blue", 10..=16)
+                .frontier(&games),
+            Some(rgb(21, 13, 15))
+        );
+    }
+}
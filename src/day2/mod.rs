@@ -1,8 +1,228 @@
 // https://adventofcode.com/2023/day/2
 
+use std::collections::BTreeMap;
+
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
+pub use feasibility::{
+    feasibility, feasibility_of_all, pareto_frontier, smallest_bag, BagRange, Bags, Feasibility,
+};
+
+mod feasibility;
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// The colors the puzzle asks about.
+pub const RGB: [&str; 3] = ["red", "green", "blue"];
+
+/// A number of cubes of each color. Colors that aren't listed have no cubes, and colors with no
+/// cubes aren't listed, so equal sets compare equal.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CubeSet {
+    counts: BTreeMap<String, u32>,
+}
+
+impl CubeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `n` cubes of `color`.
+    pub fn with(mut self, color: impl Into<String>, n: u32) -> Self {
+        if n > 0 {
+            *self.counts.entry(color.into()).or_default() += n;
+        }
+        self
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    /// Whether every cube in `other` could have come out of this set.
+    pub fn contains(&self, other: &CubeSet) -> bool {
+        other
+            .counts
+            .iter()
+            .all(|(color, &n)| n <= self.count(color))
+    }
+
+    /// The smallest set containing both this one and `other`.
+    pub fn union(mut self, other: &CubeSet) -> Self {
+        for (color, &n) in other.counts.iter() {
+            let count = self.counts.entry(color.clone()).or_default();
+            *count = (*count).max(n);
+        }
+        self
+    }
+
+    /// How many cubes there are of every color together.
+    pub fn total(&self) -> u64 {
+        self.counts.values().map(|&n| n as u64).sum()
+    }
+
+    /// The numbers of cubes of `colors` multiplied together.
+    pub fn power(&self, colors: &[&str]) -> u64 {
+        colors
+            .iter()
+            .map(|color| self.count(color) as u64)
+            .product()
+    }
+}
+
+/// One game: the cubes drawn from the bag each time, and the game's ID.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Whether every draw could have come out of `bag`.
+    pub fn is_possible(&self, bag: &CubeSet) -> bool {
+        self.draws.iter().all(|draw| bag.contains(draw))
+    }
+
+    /// The fewest cubes the bag could have held for this game.
+    pub fn minimum_bag(&self) -> CubeSet {
+        self.draws.iter().fold(CubeSet::new(), CubeSet::union)
+    }
+
+    /// The power of the minimum bag, counting `colors`.
+    pub fn power(&self, colors: &[&str]) -> u64 {
+        self.minimum_bag().power(colors)
+    }
+}
+
+/// Parse a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+fn parse_game(line: Line) -> InputResult<Game> {
+    let (game, draws) = line.split_once(line.text, ":")?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| line.error(game, "expected `Game <id>`"))?;
+    let draws = draws
+        .split(';')
+        .map(|draw| parse_draw(line, draw))
+        .collect::<InputResult<_>>()?;
+    Ok(Game {
+        id: line.token(id.trim())?,
+        draws,
+    })
+}
+
+/// Parse the cubes of one draw, like `3 blue, 4 red`.
+fn parse_draw(line: Line, draw: &str) -> InputResult<CubeSet> {
+    draw.split(',').try_fold(CubeSet::new(), |cubes, text| {
+        let (n, color) = line.split_once(text.trim(), " ")?;
+        let color = color.trim();
+        if color.is_empty() {
+            return Err(line.error(text, "expected `<count> <color>`"));
+        }
+        Ok(cubes.with(color, line.token(n)?))
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("8"),
+        part2: Some("2286"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(2, &lines, parse_game)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, InputError};
+
+    #[test]
+    fn test_parse_game() {
+        let game = parse_game(Line::new(
+            2,
+            0,
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 1 red, 2 mauve",
+        ))
+        .unwrap();
+        assert_eq!(game.id, 3);
+        assert_eq!(game.draws.len(), 3);
+        assert_eq!(
+            game.draws[2],
+            CubeSet::new().with("mauve", 2).with("red", 1)
+        );
+        // Drawing none of a color is the same as not mentioning it.
+        let game = parse_game(Line::new(2, 0, "Game 4: 0 red, 2 blue; 0 green")).unwrap();
+        assert_eq!(game.draws[0], CubeSet::new().with("blue", 2));
+        assert_eq!(game.draws[1], CubeSet::new());
+        for text in [
+            "Game 3 8 green",
+            "Gaem 3: 8 green",
+            "Game 3: 8",
+            "Game 3: green 8",
+        ] {
+            assert!(
+                matches!(
+                    parse_game(Line::new(2, 4, text)),
+                    Err(InputError::Parse { line: 5, .. })
+                ),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_queries() {
+        let game = parse_game(Line::new(
+            2,
+            0,
+            "Game 1: 3 blue, 4 red; 2 green, 6 blue; 2 green",
+        ))
+        .unwrap();
+        let bag = CubeSet::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14);
+        assert!(game.is_possible(&bag));
+        assert!(!game.is_possible(&CubeSet::new().with("red", 4).with("green", 2)));
+        assert_eq!(
+            game.minimum_bag(),
+            CubeSet::new()
+                .with("red", 4)
+                .with("green", 2)
+                .with("blue", 6)
+        );
+        assert_eq!(game.power(&RGB), 48);
+        assert_eq!(game.power(&["red", "mauve"]), 0);
+        assert_eq!(CubeSet::new().with("mauve", 0), CubeSet::new());
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day2>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day2>(2);
+    }
 }
@@ -1,8 +1,86 @@
 // https://adventofcode.com/2023/day/20
 
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+const BROADCASTER_NAME: &str = "broadcaster";
+
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+/// One line of the module configuration, like `%a -> b, c`.
+pub struct ModuleSpec {
+    pub kind: ModuleKind,
+    pub name: String,
+    pub destinations: Vec<String>,
+}
+
+fn parse_module(line: Line) -> InputResult<ModuleSpec> {
+    static MODULE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([%&]?)(\w+) -> (.*)$").unwrap());
+    let caps = line.captures(&MODULE_REGEX)?;
+    let (prefix, name, destinations) = (&caps[1], &caps[2], caps.get(3).unwrap().as_str());
+    let kind = match prefix {
+        "" if name == BROADCASTER_NAME => ModuleKind::Broadcaster,
+        "%" => ModuleKind::FlipFlop,
+        "&" => ModuleKind::Conjunction,
+        _ => return Err(line.error(name, "expected `%` or `&` before the module name")),
+    };
+    Ok(ModuleSpec {
+        kind,
+        name: name.to_string(),
+        destinations: line.list(destinations, ',')?,
+    })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<ModuleSpec>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("example.txt"),
+            part1: Some("32000000"),
+            part2: None,
+        },
+        Example {
+            input: include_str!("example2.txt"),
+            part1: Some("11687500"),
+            part2: None,
+        },
+    ];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(20, &lines, parse_module)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day20>(1);
+    }
+
+    // The puzzle gives no example for part 2: it needs a module named `rx`.
 }
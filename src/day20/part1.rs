@@ -1,12 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
-use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use log::{debug, info, trace};
 
-use crate::common::get_input;
+use super::{ModuleKind, ModuleSpec, BROADCASTER_NAME};
 
-const BROADCASTER_NAME: &str = "broadcaster";
 const BUTTON_NAME: &str = "button";
 
 #[derive(Debug, Copy, Clone)]
@@ -111,32 +108,17 @@ struct System {
 }
 
 impl System {
-    fn parse(input: &Vec<String>) -> Option<Self> {
-        static MODULE_REGEX: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"([%&]?)(\w+) -> (.*)").unwrap());
+    fn parse(input: &[ModuleSpec]) -> Self {
         let mut modules = HashMap::new();
-        for line in input {
-            let caps = MODULE_REGEX.captures(line)?;
-            let name = caps.get(2)?.as_str();
-            let pulse_processor: Box<dyn ProcessPulse> = if name == BROADCASTER_NAME {
-                Box::<Broadcaster>::default()
-            } else {
-                let prefix = caps.get(1)?.as_str();
-                match prefix {
-                    "%" => Box::<FlipFlop>::default(),
-                    "&" => Box::<Conjunction>::default(),
-                    _ => return None,
-                }
+        for spec in input {
+            let pulse_processor: Box<dyn ProcessPulse> = match spec.kind {
+                ModuleKind::Broadcaster => Box::<Broadcaster>::default(),
+                ModuleKind::FlipFlop => Box::<FlipFlop>::default(),
+                ModuleKind::Conjunction => Box::<Conjunction>::default(),
             };
+            let destinations = spec.destinations.clone();
 
-            let destinations = caps
-                .get(3)?
-                .as_str()
-                .split(", ")
-                .map(|s| s.to_string())
-                .collect_vec();
-
-            let module_name = name.to_string();
+            let module_name = spec.name.clone();
             modules.insert(
                 module_name,
                 Module {
@@ -160,11 +142,11 @@ impl System {
             }
         }
 
-        Some(Self {
+        Self {
             modules,
             low_pulses: 0,
             high_pulses: 0,
-        })
+        }
     }
 
     fn press_button(&mut self) {
@@ -190,12 +172,11 @@ impl System {
                 }
             }
 
-            // Log this pulse
             let pulse_name = match pulse {
                 Pulse::Low => "low",
                 Pulse::High => "high",
             };
-            println!("{} -{pulse_name}-> {}", origin_name, dest_name);
+            trace!("{} -{pulse_name}-> {}", origin_name, dest_name);
 
             if let Some(dest) = self.modules.get_mut(&dest_name) {
                 // Queue next pulses
@@ -210,20 +191,20 @@ impl System {
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day20/input1.txt");
-    let mut system = System::parse(&input).expect("Failed to parse");
+pub fn solve(input: &[ModuleSpec]) -> i32 {
+    let mut system = System::parse(input);
 
     let mut high_pulses = 0;
     let mut low_pulses = 0;
     for i in 1..=1000 {
-        println!("Button press {i}");
         system.press_button();
         high_pulses += system.high_pulses;
         low_pulses += system.low_pulses;
-        println!("{} high, {} low", system.high_pulses, system.low_pulses);
-        println!("---");
+        debug!(
+            "Button press {i}: {} high, {} low",
+            system.high_pulses, system.low_pulses
+        );
     }
-    println!("Total: {} high, {} low", high_pulses, low_pulses);
-    println!("Answer: {}", high_pulses * low_pulses);
+    info!("Total: {} high, {} low", high_pulses, low_pulses);
+    high_pulses * low_pulses
 }
@@ -1,12 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
-use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use log::debug;
 
-use crate::common::get_input;
+use super::{ModuleKind, ModuleSpec, BROADCASTER_NAME};
 
-const BROADCASTER_NAME: &str = "broadcaster";
 const BUTTON_NAME: &str = "button";
 const RX_NAME: &str = "rx";
 
@@ -111,32 +108,17 @@ struct System {
 }
 
 impl System {
-    fn parse(input: &Vec<String>) -> Option<Self> {
-        static MODULE_REGEX: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"([%&]?)(\w+) -> (.*)").unwrap());
+    fn parse(input: &[ModuleSpec]) -> Self {
         let mut modules = HashMap::new();
-        for line in input {
-            let caps = MODULE_REGEX.captures(line)?;
-            let name = caps.get(2)?.as_str();
-            let pulse_processor: Box<dyn ProcessPulse> = if name == BROADCASTER_NAME {
-                Box::<Broadcaster>::default()
-            } else {
-                let prefix = caps.get(1)?.as_str();
-                match prefix {
-                    "%" => Box::<FlipFlop>::default(),
-                    "&" => Box::<Conjunction>::default(),
-                    _ => return None,
-                }
+        for spec in input {
+            let pulse_processor: Box<dyn ProcessPulse> = match spec.kind {
+                ModuleKind::Broadcaster => Box::<Broadcaster>::default(),
+                ModuleKind::FlipFlop => Box::<FlipFlop>::default(),
+                ModuleKind::Conjunction => Box::<Conjunction>::default(),
             };
+            let destinations = spec.destinations.clone();
 
-            let destinations = caps
-                .get(3)?
-                .as_str()
-                .split(", ")
-                .map(|s| s.to_string())
-                .collect_vec();
-
-            let module_name = name.to_string();
+            let module_name = spec.name.clone();
             modules.insert(
                 module_name,
                 Module {
@@ -160,10 +142,10 @@ impl System {
             }
         }
 
-        Some(Self {
+        Self {
             modules,
             rx_active: false,
-        })
+        }
     }
 
     fn press_button(&mut self) {
@@ -177,13 +159,10 @@ impl System {
 
         while !q.is_empty() {
             let (pulse, origin_name, dest_name) = q.pop_front().expect("queue should not be empty");
-            match pulse {
-                Pulse::Low => {
-                    if dest_name == RX_NAME {
-                        self.rx_active = true;
-                    }
+            if let Pulse::Low = pulse {
+                if dest_name == RX_NAME {
+                    self.rx_active = true;
                 }
-                _ => {}
             }
 
             // Log this pulse
@@ -193,7 +172,7 @@ impl System {
                     Pulse::High => "high",
                 };
                 if pulse_name == "high" {
-                    println!("{} -{pulse_name}-> {}", origin_name, dest_name);
+                    debug!("{} -{pulse_name}-> {}", origin_name, dest_name);
                 }
             }
 
@@ -210,14 +189,13 @@ impl System {
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day20/input1.txt");
-    let mut system = System::parse(&input).expect("Failed to parse");
+pub fn solve(input: &[ModuleSpec]) -> u64 {
+    let mut system = System::parse(input);
 
     let mut presses = 0;
     while !system.rx_active {
         presses += 1;
         system.press_button();
     }
-    println!("Rx active after {presses} presses.")
+    presses
 }
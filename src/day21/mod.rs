@@ -1,8 +1,54 @@
 // https://adventofcode.com/2023/day/21
 
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("16"),
+        part2: None,
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let map = Grid::parse(21, &lines, |c| matches!(c, 'S' | '.' | '#').then_some(c))?;
+        if map.position(|&c| c == 'S').is_none() {
+            return Err(InputError::malformed(21, "no starting position `S`"));
+        }
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::get_input_from_str;
+
+    #[test]
+    fn test_part1_examples() {
+        // The example's answer is for 6 steps rather than the real input's 64.
+        let example = &Day21::EXAMPLES[0];
+        let map = Day21::parse(get_input_from_str(example.input)).unwrap();
+        assert_eq!(
+            part1::solve_steps(&map, 6).to_string(),
+            example.part1.unwrap()
+        );
+    }
+
+    // The puzzle gives no example for part 2's step count.
 }
@@ -1,8 +1,83 @@
 // https://adventofcode.com/2023/day/22
 
+use crate::common::geometry::Point3;
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// The cubes at the two ends of a brick, like `1,0,1~1,2,1`.
+pub type Ends = (Point3<i32>, Point3<i32>);
+
+fn parse_end(line: Line, text: &str) -> InputResult<Point3<i32>> {
+    match line.list::<i32>(text, ',')?[..] {
+        [x, y, z] if x >= 0 && y >= 0 && z >= 1 => Ok(Point3::new(x, y, z)),
+        [_, _, _] => Err(line.error(text, "expected x and y from 0 and z from 1")),
+        _ => Err(line.error(text, "expected `x,y,z`")),
+    }
+}
+
+fn parse_ends(line: Line) -> InputResult<Ends> {
+    let (end1, end2) = line.split_once(line.text, "~")?;
+    Ok((parse_end(line, end1)?, parse_end(line, end2)?))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Ends>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("5"),
+        part2: Some("7"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(22, &lines, parse_ends)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, InputError};
+
+    #[test]
+    fn test_parse_ends() {
+        assert_eq!(
+            parse_ends(Line::new(22, 0, "1,0,1~1,2,1")).unwrap(),
+            (Point3::new(1, 0, 1), Point3::new(1, 2, 1))
+        );
+        assert!(matches!(
+            parse_ends(Line::new(22, 1, "1,0,1~1,2,0")),
+            Err(InputError::Parse {
+                line: 2,
+                column: 7,
+                ..
+            })
+        ));
+        assert!(parse_ends(Line::new(22, 0, "1,0,1~1,2")).is_err());
+        assert!(parse_ends(Line::new(22, 0, "1,0,1,1,2,1")).is_err());
+        assert!(parse_ends(Line::new(22, 0, "1,x,1~1,2,1")).is_err());
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day22>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day22>(2);
+    }
 }
@@ -1,23 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use log::trace;
 
-use crate::common::get_input;
+use crate::common::geometry::Point3;
 
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-struct Point {
-    x: i32,
-    y: i32,
-    z: i32,
-}
+use super::Ends;
 
-impl Point {
-    fn new(x: i32, y: i32, z: i32) -> Self {
-        Self { x, y, z }
-    }
-}
+type Point = Point3<i32>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Brick {
@@ -26,16 +16,7 @@ struct Brick {
 }
 
 impl Brick {
-    fn new(line: &str, id: usize) -> Self {
-        static MODULE_REGEX: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"(\d+),(\d+),(\d+)~(\d+),(\d+),(\d+)").unwrap());
-        let caps = MODULE_REGEX.captures(line).unwrap();
-        let nums =
-            [1, 2, 3, 4, 5, 6].map(|i| caps.get(i).unwrap().as_str().parse::<i32>().unwrap());
-        let (end1, end2) = (
-            Point::new(nums[0], nums[1], nums[2]),
-            Point::new(nums[3], nums[4], nums[5]),
-        );
+    fn new(&(end1, end2): &Ends, id: usize) -> Self {
         Self {
             id,
             points: Self::points(end1, end2),
@@ -57,7 +38,7 @@ impl Brick {
         }
 
         // Sort points by z value
-        p.sort_by(|p1, p2| p1.z.cmp(&p2.z));
+        p.sort_by_key(|p1| p1.z);
         p
     }
 }
@@ -80,14 +61,14 @@ impl Tower {
         let mut cells = HashMap::new();
 
         for brick in bricks.iter() {
-            // println!("Place brick {} at {:?}", brick.id, brick.points);
+            trace!("Place brick {} at {:?}", brick.id, brick.points);
             for point in brick.points.iter() {
                 max_x = i32::max(max_x, point.x);
                 max_y = i32::max(max_y, point.y);
                 max_z = i32::max(max_z, point.z);
 
                 cells.insert(*point, brick.id);
-                // println!("Brick {} is at {:?}", brick.id, point);
+                trace!("Brick {} is at {:?}", brick.id, point);
             }
         }
 
@@ -110,7 +91,7 @@ impl Tower {
                 for y in 0..=self.max_y {
                     let point = Point { x, y, z };
                     if let Some(brick) = self.cells.get(&point) {
-                        // println!("Found brick {} at {:?}", brick, point);
+                        trace!("Found brick {} at {:?}", brick, point);
                         points_with_bricks.push(point);
                     }
                 }
@@ -166,7 +147,7 @@ impl Tower {
 
         // Update brick points
         let brick = self.bricks.get_mut(brick_id).unwrap();
-        // println!("Moved brick {} to {:?}", brick_id, new_brick_points);
+        trace!("Moved brick {} to {:?}", brick_id, new_brick_points);
         brick.points = new_brick_points;
     }
 
@@ -237,21 +218,21 @@ impl Tower {
 
     fn count_chain_reactions(&self) -> i32 {
         let supported_bricks = self.calculate_supported_bricks();
-        // println!("Supported bricks: {:?}", supported_bricks);
+        trace!("Supported bricks: {:?}", supported_bricks);
         let base_bricks = self.calculate_base_bricks();
-        // println!("Supporting bricks: {:?}", base_bricks);
+        trace!("Supporting bricks: {:?}", base_bricks);
 
         let mut res = 0;
         let removed_bricks = HashSet::new();
         for brick in self.bricks.iter() {
-            // println!("Simulate remove {}", brick.id);
+            trace!("Simulate remove {}", brick.id);
             let n = Self::simulate_remove_brick(
                 brick.id,
                 &supported_bricks,
                 &base_bricks,
                 &removed_bricks,
             );
-            // println!("{} bricks fall if {} is removed", n.len(), brick.id);
+            trace!("{} bricks fall if {} is removed", n.len(), brick.id);
             res += n.len();
         }
         res as i32
@@ -303,20 +284,18 @@ impl Tower {
 fn min_max(a: i32, b: i32) -> (i32, i32) {
     let min = i32::min(a, b);
     let max = i32::max(a, b);
-    (a, b)
+    (min, max)
 }
 
-pub fn run() {
-    let input = get_input("src/day22/input1.txt");
+pub fn solve(input: &[Ends]) -> i32 {
     let bricks = input
         .iter()
         .enumerate()
-        .map(|(id, line)| Brick::new(line.as_str(), id))
+        .map(|(id, ends)| Brick::new(ends, id))
         .collect_vec();
     let mut tower = Tower::new(bricks);
     tower.collapse();
-    // println!("{:?}", tower.cells.len());
+    trace!("{} cells in the tower", tower.cells.len());
 
-    let ans = tower.count_chain_reactions();
-    println!("{}", ans);
+    tower.count_chain_reactions()
 }
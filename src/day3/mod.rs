@@ -1,8 +1,47 @@
 // https://adventofcode.com/2023/day/3
 
+use crate::common::grid::Grid;
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("4361"),
+        part2: Some("467835"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        Grid::parse(3, &lines, Some)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day3>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day3>(2);
+    }
 }
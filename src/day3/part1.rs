@@ -2,44 +2,10 @@
 
 use std::ops::RangeInclusive;
 
+use log::{debug, trace};
 use rangemap::RangeInclusiveMap;
 
-use crate::common::get_input;
-
-#[derive(Debug)]
-struct Point {
-    row: usize,
-    col: usize,
-}
-
-impl Point {
-    fn new(row: usize, col: usize) -> Self {
-        Self { row, col }
-    }
-
-    fn neighbors(&self, n_rows: usize, n_cols: usize) -> Vec<Point> {
-        let mut neighbors = Vec::new();
-
-        for (dx, dy) in [
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-            (-1, 0),
-            (1, 0),
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-        ] {
-            let row = self.row as i32 + dy;
-            let col = self.col as i32 + dx;
-            if (0..n_rows as i32).contains(&row) && (0..n_cols as i32).contains(&col) {
-                neighbors.push(Point::new(row as usize, col as usize));
-            }
-        }
-
-        neighbors
-    }
-}
+use crate::common::grid::{Grid, Pos};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct SchematicNumber {
@@ -47,35 +13,23 @@ struct SchematicNumber {
     range: RangeInclusive<usize>,
 }
 
-pub fn run() {
-    let input = get_input("src/day3/input1.txt");
-
-    // Handle 0 case
-    let n_rows = input.len();
-    if n_rows == 0 {
-        println!("0");
-        return;
-    }
-    let n_cols = input[0].len();
-    if n_cols == 0 {
-        println!("0");
-        return;
-    }
+pub fn solve(input: &Grid<char>) -> i32 {
+    let n_rows = input.height();
+    let n_cols = input.width();
 
     // Collect all schematic numbers and other symbols
     let mut schematic_numbers = Vec::new();
     let mut symbol_locations = Vec::new();
 
-    let mut row: usize = 0;
-    for line in input {
+    for (row, line) in input.rows().enumerate() {
         let mut schematic_numbers_in_row = RangeInclusiveMap::new();
         let mut curr_num_chars = Vec::new();
         let mut curr_num_start_col: Option<usize> = None;
         let mut col: usize = 0;
-        for c in line.chars() {
+        for &c in line {
             if is_number(c) {
                 // Append c to curr_num_chars
-                if curr_num_chars.len() == 0 {
+                if curr_num_chars.is_empty() {
                     curr_num_start_col = Some(col);
                 }
                 curr_num_chars.push(c);
@@ -97,7 +51,7 @@ pub fn run() {
 
                 if c != '.' {
                     // Found a symbol location
-                    symbol_locations.push(Point { row, col });
+                    symbol_locations.push(Pos::new(row, col));
                 }
             }
             col += 1;
@@ -117,29 +71,28 @@ pub fn run() {
         }
 
         schematic_numbers.push(schematic_numbers_in_row);
-        row += 1;
     }
 
-    // println!("Schematic numbers: {:?}", schematic_numbers);
-    // println!("Symbol locations: {:?}", symbol_locations);
-    println!("Parsed {} rows, {} cols", n_rows, n_cols);
+    trace!("Schematic numbers: {:?}", schematic_numbers);
+    trace!("Symbol locations: {:?}", symbol_locations);
+    debug!("Parsed {} rows, {} cols", n_rows, n_cols);
 
     let mut sum = 0;
     for symbol_location in symbol_locations {
-        for neighbor in symbol_location.neighbors(n_rows, n_cols) {
+        for neighbor in input.neighbors8(symbol_location) {
             let schematic_numbers_in_row = &mut schematic_numbers[neighbor.row];
             if let Some(found_number) = schematic_numbers_in_row.get(&neighbor.col) {
-                // println!("Found {:?}", found_number);
+                trace!("Found {:?}", found_number);
                 sum += found_number.value;
                 schematic_numbers_in_row.remove(found_number.range.clone());
             }
         }
     }
-    println!("{}", sum);
+    sum
 }
 
 fn is_number(c: char) -> bool {
-    c >= '0' && c <= '9'
+    c.is_ascii_digit()
 }
 
 fn parse_num(chars: Vec<char>) -> i32 {
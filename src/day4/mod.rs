@@ -1,8 +1,73 @@
 // https://adventofcode.com/2023/day/4
 
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// The winning numbers and the numbers you have on one scratchcard.
+pub type Card = (Vec<i32>, Vec<i32>);
+
+fn parse_line_numbers(line: Line) -> InputResult<Card> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+    let (winning, mine) = line.split_once(numbers, "|")?;
+    Ok((line.ints(winning)?, line.ints(mine)?))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("13"),
+        part2: Some("30"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(4, &lines, parse_line_numbers)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, InputError};
+
+    #[test]
+    fn test_parse_line_numbers() {
+        assert_eq!(
+            parse_line_numbers(Line::new(
+                4,
+                0,
+                "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1"
+            ))
+            .unwrap(),
+            (vec![1, 21, 53, 59, 44], vec![69, 82, 63, 72, 16, 21, 14, 1])
+        );
+        assert!(matches!(
+            parse_line_numbers(Line::new(4, 2, "Card 3: 1 21 53")),
+            Err(InputError::Parse { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day4>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day4>(2);
+    }
 }
@@ -1,33 +1,16 @@
 use std::collections::HashSet;
 
-use crate::common::get_input;
-
-pub fn run() {
-    let input = get_input("src/day4/input1.txt");
+use super::Card;
 
+pub fn solve(input: &[Card]) -> i32 {
     let mut sum = 0;
     for line in input {
-        sum += get_scratchcard_value(&parse_line_numbers(line));
+        sum += get_scratchcard_value(line);
     }
-    println!("{}", sum);
-}
-
-fn parse_line_numbers(line: String) -> (Vec<i32>, Vec<i32>) {
-    let numbers: Vec<&str> = line.split(": ").collect();
-    let numbers: Vec<&str> = numbers[1].split(" | ").collect();
-    let (winning, mine) = (numbers[0], numbers[1]);
-    let winning = winning
-        .split_whitespace()
-        .map(|n| n.to_string().parse::<i32>().unwrap())
-        .collect::<Vec<i32>>();
-    let mine = mine
-        .split_whitespace()
-        .map(|n| n.to_string().parse::<i32>().unwrap())
-        .collect::<Vec<i32>>();
-    return (winning, mine);
+    sum
 }
 
-fn get_scratchcard_value(line_numbers: &(Vec<i32>, Vec<i32>)) -> i32 {
+fn get_scratchcard_value(line_numbers: &Card) -> i32 {
     let (winning, mine) = line_numbers;
     let w_set: HashSet<i32> = HashSet::from_iter(winning.iter().cloned());
 
@@ -47,14 +30,8 @@ fn get_scratchcard_value(line_numbers: &(Vec<i32>, Vec<i32>)) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_parse_line_numbers() {
-        assert_eq!(
-            parse_line_numbers("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".to_string()),
-            (vec![1, 21, 53, 59, 44], vec![69, 82, 63, 72, 16, 21, 14, 1])
-        );
-    }
+    use crate::common::parse::Line;
+    use crate::day4::parse_line_numbers;
 
     #[test]
     fn test_get_scratchcard_value() {
@@ -66,10 +43,8 @@ mod tests {
             ("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36", 0),
             ("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11", 0),
         ] {
-            assert_eq!(
-                get_scratchcard_value(&parse_line_numbers(line.to_string())),
-                val
-            );
+            let line_numbers = parse_line_numbers(Line::new(4, 0, line)).unwrap();
+            assert_eq!(get_scratchcard_value(&line_numbers), val);
         }
     }
 }
@@ -1,8 +1,109 @@
 // https://adventofcode.com/2023/day/5
 
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<AlmanacMap>,
+}
+
+/// One `<src>-to-<dst> map:` block.
+pub struct AlmanacMap {
+    pub src: String,
+    pub dst: String,
+    pub ranges: Vec<MapRange>,
+}
+
+pub struct MapRange {
+    pub dst_start: u64,
+    pub src_start: u64,
+    pub len: u64,
+}
+
+fn parse_map(start: usize, block: &[String]) -> InputResult<AlmanacMap> {
+    let title = Line::new(5, start, &block[0]);
+    let name = title
+        .text
+        .strip_suffix(" map:")
+        .ok_or_else(|| title.error(title.text, "expected `<src>-to-<dst> map:`"))?;
+    let (src, dst) = title.split_once(name, "-to-")?;
+    let ranges = block[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| {
+            let line = Line::new(5, start + 1 + i, text);
+            match line.ints(text)?[..] {
+                [dst_start, src_start, len] => Ok(MapRange {
+                    dst_start,
+                    src_start,
+                    len,
+                }),
+                _ => Err(line.error(text, "expected a destination, source and length")),
+            }
+        })
+        .collect::<InputResult<_>>()?;
+    Ok(AlmanacMap {
+        src: src.to_string(),
+        dst: dst.to_string(),
+        ranges,
+    })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("35"),
+        part2: Some("46"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let mut blocks = parse::blocks(&lines);
+        let Some((start, [seeds_text])) = blocks.next() else {
+            return Err(InputError::malformed(
+                5,
+                "expected the seeds on the first line",
+            ));
+        };
+        let seeds_line = Line::new(5, start, seeds_text);
+        let (key, seeds) = seeds_line.key_values()?;
+        if key != "seeds" {
+            return Err(seeds_line.error(key, "expected `seeds:`"));
+        }
+        let maps = blocks
+            .map(|(start, block)| parse_map(start, block))
+            .collect::<InputResult<_>>()?;
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day5>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day5>(2);
+    }
 }
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
-use crate::common::get_input;
+use log::debug;
+
+use super::Almanac;
 
 #[derive(Clone, Debug)]
 struct MatMapType {
-    src: String,
     dst: String,
     maps: Vec<MatMap>,
 }
@@ -28,57 +29,29 @@ struct MatMap {
     range_len: u64,
 }
 
-pub fn run() {
-    let input = get_input("src/day5/input1.txt");
-
-    let seeds: Vec<&str> = input[0].split(": ").collect();
-    let seeds: Vec<u64> = seeds[1]
-        .split_ascii_whitespace()
-        .map(|n| n.to_string().parse::<u64>().unwrap())
-        .collect();
+pub fn solve(input: &Almanac) -> u64 {
+    let seeds = input.seeds.clone();
 
-    let mut all_types = HashMap::new();
-    let mut curr_map_type: Option<MatMapType> = None;
-    for line in input.iter() {
-        if line.trim() == "" {
-            if let Some(curr_map_t) = curr_map_type {
-                // This ends a map type
-                let src = curr_map_t.src.clone();
-                all_types.insert(src, curr_map_t.clone());
-            }
-            curr_map_type = None;
-        } else if line.ends_with("map:") {
-            // This starts a map type
-            let line_split: Vec<&str> = line.split_ascii_whitespace().collect();
-            let map_type_str: Vec<&str> = line_split[0].split_ascii_whitespace().collect();
-            let map_type_tokens: Vec<&str> = map_type_str[0].split("-").collect();
-            let map_from = map_type_tokens[0];
-            let map_to = map_type_tokens[2];
-            curr_map_type = Some(MatMapType {
-                src: map_from.to_string(),
-                dst: map_to.to_string(),
-                maps: Vec::new(),
-            });
-        } else if curr_map_type.is_some() {
-            // This adds a map to the type
-            let map_nums: Vec<u64> = line
-                .split_ascii_whitespace()
-                .map(|n| n.to_string().parse::<u64>().unwrap())
+    let all_types: HashMap<&str, MatMapType> = input
+        .maps
+        .iter()
+        .map(|map_type| {
+            let maps = map_type
+                .ranges
+                .iter()
+                .map(|range| MatMap {
+                    dst_range_start: range.dst_start,
+                    src_range_start: range.src_start,
+                    range_len: range.len,
+                })
                 .collect();
-            let map_type = curr_map_type.as_mut().unwrap();
-            let map = MatMap {
-                dst_range_start: map_nums[0],
-                src_range_start: map_nums[1],
-                range_len: map_nums[2],
+            let curr_map_t = MatMapType {
+                dst: map_type.dst.clone(),
+                maps,
             };
-            map_type.maps.push(map);
-        }
-    }
-    if let Some(curr_map_t) = curr_map_type {
-        // This ends a map type
-        let src = curr_map_t.src.clone();
-        all_types.insert(src, curr_map_t.clone());
-    }
+            (map_type.src.as_str(), curr_map_t)
+        })
+        .collect();
 
     // Find the lowest "location" number that coresponds to any of the initial "seed"s
     let mut min_loc_num: Option<u64> = None;
@@ -92,7 +65,7 @@ pub fn run() {
         }
 
         let location_num = mat;
-        println!("{location_num}");
+        debug!("Seed {seed} is at location {location_num}");
         if min_loc_num.is_none() {
             min_loc_num = Some(location_num);
         } else {
@@ -100,5 +73,5 @@ pub fn run() {
         }
     }
 
-    println!("min={:?}", min_loc_num.unwrap());
+    min_loc_num.unwrap()
 }
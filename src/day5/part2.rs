@@ -1,12 +1,12 @@
 // WIP - unsolved
-use indicatif::ProgressBar;
+use log::debug;
 use std::{collections::HashMap, ops::Range};
 
-use crate::common::get_input;
+use super::Almanac;
+use crate::common::progress::Progress;
 
 #[derive(Clone, Debug)]
 struct MatMapType {
-    src: String,
     dst: String,
     maps: Vec<MatMap>,
 }
@@ -30,24 +30,8 @@ struct MatMap {
     range_len: u64,
 }
 
-impl MatMap {
-    fn contains(&self, n: u64) -> bool {
-        (self.src_range_start..(self.src_range_start + self.range_len)).contains(&n)
-    }
-
-    fn src_end(&self) -> u64 {
-        self.src_range_start + self.range_len
-    }
-}
-
-pub fn run() {
-    let input = get_input("src/day5/input1.txt");
-
-    let seed_strs: Vec<&str> = input[0].split(": ").collect();
-    let seed_pairs: Vec<u64> = seed_strs[1]
-        .split_ascii_whitespace()
-        .map(|n| n.to_string().parse::<u64>().unwrap())
-        .collect();
+pub fn solve(input: &Almanac) -> u64 {
+    let seed_pairs = &input.seeds;
     let mut seed_ranges: Vec<Range<u64>> = seed_pairs
         .chunks(2)
         .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
@@ -55,59 +39,37 @@ pub fn run() {
     seed_ranges.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap());
     let seed_ranges = seed_ranges;
 
-    let mut all_types = HashMap::new();
-    let mut curr_map_type: Option<MatMapType> = None;
-    for line in input.iter() {
-        if line.trim() == "" {
-            if let Some(curr_map_t) = curr_map_type {
-                // This ends a map type
-                let src = curr_map_t.src.clone();
-                all_types.insert(src, curr_map_t.clone());
-            }
-            curr_map_type = None;
-        } else if line.ends_with("map:") {
-            // This starts a map type
-            let line_split: Vec<&str> = line.split_ascii_whitespace().collect();
-            let map_type_str: Vec<&str> = line_split[0].split_ascii_whitespace().collect();
-            let map_type_tokens: Vec<&str> = map_type_str[0].split("-").collect();
-            let map_from = map_type_tokens[0];
-            let map_to = map_type_tokens[2];
-            curr_map_type = Some(MatMapType {
-                src: map_from.to_string(),
-                dst: map_to.to_string(),
-                maps: Vec::new(),
-            });
-        } else if curr_map_type.is_some() {
-            // This adds a map to the type
-            let map_nums: Vec<u64> = line
-                .split_ascii_whitespace()
-                .map(|n| n.to_string().parse::<u64>().unwrap())
+    let all_types: HashMap<&str, MatMapType> = input
+        .maps
+        .iter()
+        .map(|map_type| {
+            let maps = map_type
+                .ranges
+                .iter()
+                .map(|range| MatMap {
+                    dst_range_start: range.dst_start,
+                    src_range_start: range.src_start,
+                    range_len: range.len,
+                })
                 .collect();
-            let map_type = curr_map_type.as_mut().unwrap();
-            let map = MatMap {
-                dst_range_start: map_nums[0],
-                src_range_start: map_nums[1],
-                range_len: map_nums[2],
+            let curr_map_t = MatMapType {
+                dst: map_type.dst.clone(),
+                maps,
             };
-            map_type.maps.push(map);
-        }
-    }
-    if let Some(curr_map_t) = curr_map_type {
-        // This ends a map type
-        let src = curr_map_t.src.clone();
-        all_types.insert(src, curr_map_t.clone());
-    }
+            (map_type.src.as_str(), curr_map_t)
+        })
+        .collect();
 
     // Find the lowest "location" number that coresponds to any of the initial "seed"s
     let mut min_loc_num: Option<u64> = None;
-    println!("{:?}", seed_ranges);
+    debug!("Seed ranges: {:?}", seed_ranges);
     let total_seeds = seed_ranges.iter().fold(0, |acc, r| acc + r.end - r.start);
-    let bar = ProgressBar::new(total_seeds);
+    let progress = Progress::new("Seeds", total_seeds);
 
     for seed_range in seed_ranges {
         // TODO this approach is too slow!
         for seed in seed_range {
-            bar.inc(1);
+            progress.inc(1);
             let mut mat = seed;
             let mut map_type_name = "seed";
             while map_type_name != "location" {
@@ -124,7 +86,7 @@ pub fn run() {
             }
         }
     }
-    bar.finish();
+    progress.finish();
 
-    println!("min={:?}", min_loc_num.unwrap());
+    min_loc_num.unwrap()
 }
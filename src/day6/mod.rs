@@ -1,8 +1,76 @@
 // https://adventofcode.com/2023/day/6
 
+use crate::common::parse::Line;
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+/// The time allowed for each race and the record distance for it.
+pub struct Races {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+}
+
+fn parse_row(idx: usize, text: &str, expected_key: &str) -> InputResult<Vec<u64>> {
+    let line = Line::new(6, idx, text);
+    let (key, values) = line.key_values()?;
+    if key != expected_key {
+        return Err(line.error(key, format!("expected `{expected_key}:`")));
+    }
+    Ok(values)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("288"),
+        part2: Some("71503"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let [time_line, distance_line] = lines.as_slice() else {
+            return Err(InputError::malformed(
+                6,
+                "expected a line of times and a line of distances",
+            ));
+        };
+        let times = parse_row(0, time_line, "Time")?;
+        let distances = parse_row(1, distance_line, "Distance")?;
+        if times.len() != distances.len() {
+            return Err(InputError::malformed(
+                6,
+                "every race needs a time and a distance",
+            ));
+        }
+        Ok(Races { times, distances })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day6>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day6>(2);
+    }
 }
@@ -1,24 +1,10 @@
-use itertools::Itertools;
+use log::debug;
 
-use crate::common::get_input;
+use super::Races;
 
-pub fn run() {
-    let input = get_input("src/day6/input1.txt");
-
-    let times = {
-        let times_str = input[0].split_ascii_whitespace().collect_vec();
-        &times_str[1..times_str.len()]
-            .iter()
-            .map(|s| s.parse::<i32>().unwrap())
-            .collect_vec()
-    };
-    let distances = {
-        let dist_str = input[1].split_ascii_whitespace().collect_vec();
-        &dist_str[1..dist_str.len()]
-            .iter()
-            .map(|s| s.parse::<i32>().unwrap())
-            .collect_vec()
-    };
+pub fn solve(input: &Races) -> usize {
+    let times = &input.times;
+    let distances = &input.distances;
 
     debug_assert_eq!(times.len(), distances.len());
 
@@ -28,14 +14,13 @@ pub fn run() {
         let record_dist = distances[i];
 
         let ways_to_win = (1..race_time)
-            .map(|charge_time| (race_time - charge_time) as i64 * charge_time as i64)
-            .filter(|&dist| dist > record_dist as i64)
+            .map(|charge_time| (race_time - charge_time) * charge_time)
+            .filter(|&dist| dist > record_dist)
             .count();
 
         product *= ways_to_win;
-        println!("{ways_to_win} ways to win against time {race_time}");
+        debug!("{ways_to_win} ways to win against time {race_time}");
     }
 
-    println!("{:?}, {:?}", times, distances);
-    println!("Product: {product}");
+    product
 }
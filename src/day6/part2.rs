@@ -1,23 +1,18 @@
 use itertools::Itertools;
 
-use crate::common::get_input;
+use super::Races;
 
-pub fn run() {
-    let input = get_input("src/day6/input1.txt");
+// The numbers are really one number with bad kerning.
+fn join_digits(numbers: &[u64]) -> u64 {
+    numbers.iter().join("").parse().unwrap()
+}
 
-    let race_time = input[0].split(":").collect_vec()[1]
-        .replace(" ", "")
-        .parse::<i32>()
-        .unwrap();
-    let record_dist = input[1].split(":").collect_vec()[1]
-        .replace(" ", "")
-        .parse::<i64>()
-        .unwrap();
+pub fn solve(input: &Races) -> usize {
+    let race_time = join_digits(&input.times);
+    let record_dist = join_digits(&input.distances);
 
-    let ways_to_win = (1..race_time)
-        .map(|charge_time| (race_time - charge_time) as i64 * charge_time as i64)
+    (1..race_time)
+        .map(|charge_time| (race_time - charge_time) * charge_time)
         .filter(|&dist| dist > record_dist)
-        .count();
-
-    println!("Ways to win: {ways_to_win}");
+        .count()
 }
@@ -1,8 +1,85 @@
 // https://adventofcode.com/2023/day/7
 
+use crate::common::{column_of, parse_token, Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+const CARDS: &str = "23456789TJQKA";
+const HAND_SIZE: usize = 5;
+
+/// A hand of cards as written in the input, and its bid.
+pub struct Play {
+    cards: String,
+    bid: u32,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Play>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("6440"),
+        part2: Some("5905"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+                if tokens.len() != 2 {
+                    return Err(InputError::parse(7, i, 0, "expected a hand and a bid"));
+                }
+                let (cards, bid) = (tokens[0], tokens[1]);
+                if let Some((c, card)) = cards.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+                    return Err(InputError::parse(
+                        7,
+                        i,
+                        column_of(line, cards) + c,
+                        format!("unknown card `{card}`"),
+                    ));
+                }
+                if cards.len() != HAND_SIZE {
+                    return Err(InputError::parse(
+                        7,
+                        i,
+                        column_of(line, cards),
+                        format!("a hand has {HAND_SIZE} cards, found {}", cards.len()),
+                    ));
+                }
+                Ok(Play {
+                    cards: cards.to_string(),
+                    bid: parse_token(7, i, line, bid)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day7>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day7>(2);
+    }
 }
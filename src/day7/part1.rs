@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-use crate::common::get_input;
+use super::Play;
 
 const CARD_NAMES: [char; 13] = [
     '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
@@ -62,12 +62,7 @@ impl Hand {
     }
 
     fn hand_type(&self) -> HandType {
-        let freqs = self
-            .card_freqs()
-            .values()
-            .sorted()
-            .map(|f| *f)
-            .collect_vec();
+        let freqs = self.card_freqs().values().sorted().copied().collect_vec();
         match freqs[..] {
             [5] => HandType::FiveOfKind,
             [1, 4] => HandType::FourOfKind,
@@ -80,9 +75,7 @@ impl Hand {
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day7/input1.txt");
-
+pub fn solve(plays: &[Play]) -> u32 {
     let card_values_map: HashMap<char, usize> = CARD_NAMES
         .iter()
         .enumerate()
@@ -90,22 +83,25 @@ pub fn run() {
         .collect();
 
     let mut hands = Vec::new();
-    for line in input {
-        let split = line.split_ascii_whitespace().collect_vec();
-        let hand_values = split[0].chars().map(|c| card_values_map[&c]).collect_vec();
-        let bid = split[1].parse::<u32>().unwrap();
-        hands.push(Hand { hand_values, bid });
+    for play in plays {
+        let hand_values = play
+            .cards
+            .chars()
+            .map(|c| card_values_map[&c])
+            .collect_vec();
+        hands.push(Hand {
+            hand_values,
+            bid: play.bid,
+        });
     }
     hands.sort();
 
-    let total_winnings: u32 = hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, hand)| {
             let rank = i as u32 + 1;
             hand.bid * rank
         })
-        .sum();
-
-    println!("{:?}", total_winnings);
+        .sum()
 }
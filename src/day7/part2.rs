@@ -2,8 +2,9 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use log::trace;
 
-use crate::common::get_input;
+use super::Play;
 
 const JOKER_VALUE: usize = 0;
 const CARD_NAMES: [char; 13] = [
@@ -83,16 +84,16 @@ impl Hand {
             debug_assert_eq!(freqs.len(), self.card_freqs().len() - 1);
         }
 
-        // println!("Hand: {:?} - freqs is {:?}", self, freqs);
+        trace!("Hand: {:?} - freqs is {:?}", self, freqs);
         for _ in 0..n_jokers {
-            if freqs.len() == 0 {
+            if freqs.is_empty() {
                 // hand is only jokers
                 freqs.push(1);
             } else {
                 // Add a joker to the most frequent non-joker card's count.
                 *freqs.last_mut().unwrap() += 1;
             }
-            // println!("Processed joker - freqs is now {:?}", freqs);
+            trace!("Processed joker - freqs is now {:?}", freqs);
         }
 
         match freqs[..] {
@@ -107,9 +108,7 @@ impl Hand {
     }
 }
 
-pub fn run() {
-    let input = get_input("src/day7/input1.txt");
-
+pub fn solve(plays: &[Play]) -> u32 {
     let card_values_map: HashMap<char, usize> = CARD_NAMES
         .iter()
         .enumerate()
@@ -117,24 +116,27 @@ pub fn run() {
         .collect();
 
     let mut hands = Vec::new();
-    for line in input {
-        let split = line.split_ascii_whitespace().collect_vec();
-        let hand_values = split[0].chars().map(|c| card_values_map[&c]).collect_vec();
-        let bid = split[1].parse::<u32>().unwrap();
-        hands.push(Hand { hand_values, bid });
+    for play in plays {
+        let hand_values = play
+            .cards
+            .chars()
+            .map(|c| card_values_map[&c])
+            .collect_vec();
+        hands.push(Hand {
+            hand_values,
+            bid: play.bid,
+        });
     }
     hands.sort();
 
-    let total_winnings: u32 = hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, hand)| {
             let rank = i as u32 + 1;
             hand.bid * rank
         })
-        .sum();
-
-    println!("{:?}", total_winnings);
+        .sum()
 }
 
 #[cfg(test)]
@@ -1,8 +1,163 @@
 // https://adventofcode.com/2023/day/8
 
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::parse::{self, Line};
+use crate::common::{Answer, Example, InputError, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Where a node leads when going left or right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    pub fn next(&self, direction: Direction) -> String {
+        match direction {
+            Direction::Left => self.left.clone(),
+            Direction::Right => self.right.clone(),
+        }
+    }
+}
+
+/// The directions to follow, and the nodes by name.
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: HashMap<String, Node>,
+}
+
+fn parse_directions(line: Line) -> InputResult<Vec<Direction>> {
+    if line.text.is_empty() {
+        return Err(line.error(line.text, "expected `L` and `R` directions"));
+    }
+    line.text
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(line.error(&line.text[i..], "expected `L` or `R`")),
+        })
+        .collect()
+}
+
+fn parse_node(line: Line) -> InputResult<(String, Node)> {
+    static NODE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^(\w{3}) = \((\w{3}), (\w{3})\)$").unwrap());
+    let caps = line.captures(&NODE_REGEX)?;
+    let node = Node {
+        left: caps[2].to_string(),
+        right: caps[3].to_string(),
+    };
+    Ok((caps[1].to_string(), node))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("example.txt"),
+            part1: Some("2"),
+            part2: None,
+        },
+        Example {
+            input: include_str!("example2.txt"),
+            part1: None,
+            part2: Some("6"),
+        },
+    ];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        let mut blocks = parse::blocks(&lines);
+        let Some((start, [directions])) = blocks.next() else {
+            return Err(InputError::malformed(
+                8,
+                "expected the directions on the first line",
+            ));
+        };
+        let directions = parse_directions(Line::new(8, start, directions))?;
+        let nodes = match blocks.next() {
+            Some((start, block)) => block
+                .iter()
+                .enumerate()
+                .map(|(i, text)| parse_node(Line::new(8, start + i, text)))
+                .collect::<InputResult<HashMap<_, _>>>()?,
+            None => HashMap::new(),
+        };
+        if let Some((start, _)) = blocks.next() {
+            return Err(InputError::parse(8, start, 0, "expected no more nodes"));
+        }
+        for (name, node) in &nodes {
+            for next in [&node.left, &node.right] {
+                if !nodes.contains_key(next) {
+                    return Err(InputError::malformed(
+                        8,
+                        format!("node `{name}` leads to `{next}`, which is not in the network"),
+                    ));
+                }
+            }
+        }
+        Ok(Network { directions, nodes })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_examples, get_input_from_str};
+
+    #[test]
+    fn test_parse() {
+        let network = Day8::parse(get_input_from_str(Day8::EXAMPLES[0].input)).unwrap();
+        assert_eq!(network.directions, [Direction::Right, Direction::Left]);
+        assert_eq!(network.nodes.len(), 7);
+        assert_eq!(network.nodes["AAA"].next(Direction::Right), "CCC");
+
+        assert!(matches!(
+            Day8::parse(get_input_from_str("RXL\n\nAAA = (AAA, AAA)")),
+            Err(InputError::Parse {
+                line: 1,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day8::parse(get_input_from_str("L\n\nAAA = (AAA, ZZZ)")),
+            Err(InputError::Malformed { .. })
+        ));
+        assert!(Day8::parse(get_input_from_str("L\n\nAAA = AAA")).is_err());
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day8>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day8>(2);
+    }
 }
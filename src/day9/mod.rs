@@ -1,8 +1,48 @@
 // https://adventofcode.com/2023/day/9
 
+use crate::common::parse;
+use crate::common::{Answer, Example, InputResult, Solution};
+
 mod part1;
 mod part2;
 
-pub fn run() {
-    part2::run();
+pub struct Day9;
+
+impl Solution for Day9 {
+    /// One history of values per line.
+    type Input = Vec<Vec<i64>>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("example.txt"),
+        part1: Some("114"),
+        part2: Some("2"),
+    }];
+
+    fn parse(lines: Vec<String>) -> InputResult<Self::Input> {
+        parse::each_line(9, &lines, |line| line.ints(line.text))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::check_examples;
+
+    #[test]
+    fn test_part1_examples() {
+        check_examples::<Day9>(1);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        check_examples::<Day9>(2);
+    }
 }
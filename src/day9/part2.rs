@@ -1,9 +1,8 @@
 use itertools::Itertools;
+use log::trace;
 
-use crate::common::get_input;
-
-fn extrapolate(vals: &Vec<i64>) -> i64 {
-    // println!("vals are {:?}", vals);
+fn extrapolate(vals: &[i64]) -> i64 {
+    trace!("vals are {:?}", vals);
     if vals.iter().all(|e| *e == 0) {
         // All elements are zero
         return 0;
@@ -20,20 +19,7 @@ fn extrapolate(vals: &Vec<i64>) -> i64 {
     vals.first().unwrap() - extrapolate(&slopes)
 }
 
-pub fn run() {
-    let input = get_input("src/day9/input1.txt");
-
+pub fn solve(histories: &[Vec<i64>]) -> i64 {
     // values range from -100,000,000 to 100,000,000
-    let result: i64 = input
-        .iter()
-        .map(|line| {
-            let vals = line
-                .split_ascii_whitespace()
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect_vec();
-
-            extrapolate(&vals)
-        })
-        .sum();
-    println!("{result}");
+    histories.iter().map(|vals| extrapolate(vals)).sum()
 }
@@ -0,0 +1,78 @@
+//! Advent of Code 2023 solutions, with the helpers they share.
+//!
+//! Each `dayN` module has a `DayN` type implementing [`Solution`](common::Solution), so a day can
+//! be solved from any input with [`common::solve_str`]. [`DAYS`] lists every day, which is how the
+//! `aoc` binary finds them.
+
+use common::{sample, solve, Answer, InputResult, Sample};
+
+pub mod common;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// The template `aoc new` copies for each new day. It is built with the tests, so the code a new
+/// day starts from passes the same lints as every other day.
+#[cfg(test)]
+#[path = "_template/mod.rs"]
+mod template;
+
+/// A puzzle day and its entry points for solving and timing either part.
+pub struct Day {
+    pub number: u8,
+    /// Parse the input lines and solve the given part.
+    pub solve: fn(u8, Vec<String>) -> InputResult<Answer>,
+    /// Parse the input lines and solve the given part, timing each step.
+    pub sample: fn(u8, Vec<String>) -> InputResult<Sample>,
+}
+
+/// Every solved day, in order.
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    Day { number: 1, solve: solve::<day1::Day1>, sample: sample::<day1::Day1> },
+    Day { number: 2, solve: solve::<day2::Day2>, sample: sample::<day2::Day2> },
+    Day { number: 3, solve: solve::<day3::Day3>, sample: sample::<day3::Day3> },
+    Day { number: 4, solve: solve::<day4::Day4>, sample: sample::<day4::Day4> },
+    Day { number: 5, solve: solve::<day5::Day5>, sample: sample::<day5::Day5> },
+    Day { number: 6, solve: solve::<day6::Day6>, sample: sample::<day6::Day6> },
+    Day { number: 7, solve: solve::<day7::Day7>, sample: sample::<day7::Day7> },
+    Day { number: 8, solve: solve::<day8::Day8>, sample: sample::<day8::Day8> },
+    Day { number: 9, solve: solve::<day9::Day9>, sample: sample::<day9::Day9> },
+    Day { number: 10, solve: solve::<day10::Day10>, sample: sample::<day10::Day10> },
+    Day { number: 11, solve: solve::<day11::Day11>, sample: sample::<day11::Day11> },
+    Day { number: 12, solve: solve::<day12::Day12>, sample: sample::<day12::Day12> },
+    Day { number: 13, solve: solve::<day13::Day13>, sample: sample::<day13::Day13> },
+    Day { number: 14, solve: solve::<day14::Day14>, sample: sample::<day14::Day14> },
+    Day { number: 15, solve: solve::<day15::Day15>, sample: sample::<day15::Day15> },
+    Day { number: 16, solve: solve::<day16::Day16>, sample: sample::<day16::Day16> },
+    Day { number: 17, solve: solve::<day17::Day17>, sample: sample::<day17::Day17> },
+    Day { number: 18, solve: solve::<day18::Day18>, sample: sample::<day18::Day18> },
+    Day { number: 19, solve: solve::<day19::Day19>, sample: sample::<day19::Day19> },
+    Day { number: 20, solve: solve::<day20::Day20>, sample: sample::<day20::Day20> },
+    Day { number: 21, solve: solve::<day21::Day21>, sample: sample::<day21::Day21> },
+    Day { number: 22, solve: solve::<day22::Day22>, sample: sample::<day22::Day22> },
+];
+
+/// The registered day numbered `number`.
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
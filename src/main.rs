@@ -1,29 +1,768 @@
-#![allow(dead_code)]
-#![allow(unused_variables)]
-
-mod common;
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day3;
-mod day4;
-mod day5;
-mod day7;
-mod day8;
-mod day9;
-
-fn main() {
-    day22::run();
+//! The `aoc` command line: runs, checks, benchmarks and visualizes the solutions in the `aoc`
+//! library.
+
+use std::any::Any;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc::common::logging::{self, LogFilter};
+use aoc::common::progress::{self, ProgressMode};
+use aoc::common::report::{self, OutputFormat, ResultRecord, Status};
+use aoc::common::visualize;
+use aoc::common::{
+    Answer, AnswerStore, Baseline, InputResolver, InputResult, InputSet, PartBench, Verdict,
+};
+use aoc::day1::{self, Mode, Vocabulary};
+use aoc::{Day, DAYS};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory holding the `dayN/example.txt` and `dayN/input.txt` inputs [env: AOC_INPUT_DIR]
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// TOML file of accepted answers that runs are checked against
+    #[arg(long, global = true, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Print solutions' log messages to stderr: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Log levels to use instead, for every day (`debug`) or one day (`day20=trace`), separated by
+    /// commas
+    #[arg(long, global = true, value_name = "LEVELS")]
+    log: Option<String>,
+
+    /// How long-running solutions show progress: `bar`, `log` (info messages) or `off`. Defaults
+    /// to a bar for single days on a terminal, and off for `run --all` and `bench`
+    #[arg(long, global = true, value_name = "MODE")]
+    progress: Option<ProgressMode>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution, or every day's with `--all`
+    Run {
+        /// Day to run
+        #[arg(short, long, required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Run every registered day in parallel and print a summary table
+        #[arg(short, long, conflicts_with = "day")]
+        all: bool,
+
+        /// Part to run; both parts run if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input set: `real`, `example`, `-` for stdin, or a path to any other file
+        #[arg(short, long, default_value = "real")]
+        input: InputSet,
+
+        /// Output format: `text`, or `json` or `csv` records for scripts
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+
+        /// List the lines without a calibration value along with the total, instead of just the
+        /// answer (day 1 only)
+        #[arg(long, conflicts_with_all = ["all", "format"])]
+        report: bool,
+    },
+
+    /// Record an accepted answer so later runs are checked against it
+    Record {
+        /// Day the answer belongs to
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part the answer belongs to
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Input set the answer is for: `real`, `example`, or a path to any other file
+        #[arg(short, long, default_value = "real")]
+        input: InputSet,
+
+        /// Answer to record; the solution is run to get it if omitted
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+
+    /// Time each part over repeated runs, optionally saving or comparing against a baseline
+    Bench {
+        /// Day to benchmark; every registered day is benchmarked if omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Part to benchmark; both parts are benchmarked if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input set: `real`, `example`, or a path to any other file
+        #[arg(short, long, default_value = "real")]
+        input: InputSet,
+
+        /// Number of timed runs per part
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+
+        /// Save the results as a baseline to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the results against a baseline saved earlier
+        #[arg(long)]
+        compare: Option<PathBuf>,
+
+        /// Slowdown against the baseline, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+
+    /// Create a new day from src/_template and register it in lib.rs
+    New {
+        /// Day to create
+        #[arg(short, long)]
+        day: u8,
+    },
+
+    /// Run one part of a day and play back the frames it records in the terminal
+    Visualize {
+        /// Day to run
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part to run
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Input set: `real`, `example`, or a path to any other file
+        #[arg(short, long, default_value = "example")]
+        input: InputSet,
+
+        /// Save the last frame as a picture (.bmp, .ppm or .svg) instead of playing the frames
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Size in pixels of each cell in a saved picture
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+    },
+
+    /// List the registered days and where their real inputs are read from
+    List,
+}
+
+fn find_day(number: u8) -> Result<&'static Day, String> {
+    aoc::find_day(number).ok_or_else(|| {
+        format!("day {number} is not registered (run `aoc list` to see the available days)")
+    })
+}
+
+/// Print day 1's report of the lines each part finds no digit in.
+fn calibration_report(
+    resolver: &InputResolver,
+    day: u8,
+    part: Option<u8>,
+    input: InputSet,
+) -> Result<(), String> {
+    if day != 1 {
+        return Err(format!("day {day} has no report; only day 1 does"));
+    }
+    let lines = resolver.load(1, &input).map_err(|err| err.to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let vocabulary = Vocabulary::english();
+    for part in parts {
+        let mode = if part == 1 {
+            Mode::Numerals
+        } else {
+            Mode::Words
+        };
+        let report = day1::report(&lines, &vocabulary, mode);
+        println!("Day 1 part {part}:\n{report}");
+    }
+    Ok(())
+}
+
+fn run(
+    resolver: &InputResolver,
+    store: &AnswerStore,
+    day: u8,
+    part: Option<u8>,
+    input: InputSet,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let day = find_day(day)?;
+    let lines = resolver
+        .load(day.number, &input)
+        .map_err(|err| err.to_string())?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if format != OutputFormat::Text {
+        let results = parts
+            .into_iter()
+            .map(|part| {
+                let start = Instant::now();
+                let outcome = solve_part(day, part, lines.clone());
+                PartResult::new(store, day, part, &input, outcome, start.elapsed())
+            })
+            .collect::<Vec<_>>();
+        print_records(&results, &input, format)?;
+        return check_results(&results);
+    }
+
+    let mut n_wrong = 0;
+    for part in parts {
+        let answer = (day.solve)(part, lines.clone()).map_err(|err| err.to_string())?;
+        let verdict = store.check(day.number, part, &input, &answer);
+        if matches!(verdict, Verdict::Wrong { .. }) {
+            n_wrong += 1;
+        }
+        println!("Day {} part {part}: {answer} ({verdict})", day.number);
+    }
+    if n_wrong > 0 {
+        return Err(format!(
+            "{n_wrong} answers do not match {}",
+            store.path().display()
+        ));
+    }
+    Ok(())
+}
+
+fn record(
+    resolver: &InputResolver,
+    store: &mut AnswerStore,
+    day: u8,
+    part: u8,
+    input: InputSet,
+    answer: Option<String>,
+) -> Result<(), String> {
+    let day = find_day(day)?;
+    let answer = match answer {
+        Some(answer) => Answer::Text(answer),
+        None => {
+            let lines = resolver
+                .load(day.number, &input)
+                .map_err(|err| err.to_string())?;
+            (day.solve)(part, lines).map_err(|err| err.to_string())?
+        }
+    };
+
+    match store.record(day.number, part, &input, &answer)? {
+        Some(old) if old != answer.to_string() => println!(
+            "Day {} part {part} ({input}): {answer} (replaces {old})",
+            day.number
+        ),
+        _ => println!("Day {} part {part} ({input}): {answer}", day.number),
+    }
+    store.save()
+}
+
+/// How solving one part of one day went.
+enum Outcome {
+    Solved(Answer),
+    InputError(String),
+    Panicked(String),
+}
+
+impl Outcome {
+    fn is_ok(&self) -> bool {
+        matches!(self, Outcome::Solved(_))
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::InputError(err) => write!(f, "error: {err}"),
+            Outcome::Panicked(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+struct PartResult {
+    day: u8,
+    part: u8,
+    outcome: Outcome,
+    verdict: Verdict,
+    elapsed: Duration,
+}
+
+impl PartResult {
+    fn new(
+        store: &AnswerStore,
+        day: &Day,
+        part: u8,
+        input: &InputSet,
+        outcome: Outcome,
+        elapsed: Duration,
+    ) -> Self {
+        let verdict = match &outcome {
+            Outcome::Solved(answer) => store.check(day.number, part, input, answer),
+            _ => Verdict::Unknown,
+        };
+        Self {
+            day: day.number,
+            part,
+            outcome,
+            verdict,
+            elapsed,
+        }
+    }
+
+    fn to_record(&self, input: &InputSet) -> ResultRecord {
+        let (answer, status, detail) = match (&self.outcome, &self.verdict) {
+            (Outcome::Solved(answer), Verdict::Correct) => {
+                (Some(answer.to_string()), Status::Correct, None)
+            }
+            (Outcome::Solved(answer), Verdict::Wrong { expected }) => (
+                Some(answer.to_string()),
+                Status::Wrong,
+                Some(expected.clone()),
+            ),
+            (Outcome::Solved(answer), Verdict::Unknown) => {
+                (Some(answer.to_string()), Status::Unverified, None)
+            }
+            (Outcome::InputError(err), _) => (None, Status::Error, Some(err.clone())),
+            (Outcome::Panicked(msg), _) => (None, Status::Panicked, Some(msg.clone())),
+        };
+        ResultRecord {
+            day: self.day,
+            part: self.part,
+            input: input.to_string(),
+            answer,
+            duration: self.elapsed,
+            status,
+            detail,
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn solve_part(day: &Day, part: u8, lines: Vec<String>) -> Outcome {
+    match panic::catch_unwind(|| (day.solve)(part, lines)) {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(err)) => Outcome::InputError(err.to_string()),
+        Err(payload) => Outcome::Panicked(panic_message(payload)),
+    }
+}
+
+fn run_part(
+    resolver: &InputResolver,
+    store: &AnswerStore,
+    day: &Day,
+    part: u8,
+    input: &InputSet,
+) -> PartResult {
+    let start = Instant::now();
+    let outcome = match resolver.load(day.number, input) {
+        Ok(lines) => solve_part(day, part, lines),
+        Err(err) => Outcome::InputError(err.to_string()),
+    };
+    PartResult::new(store, day, part, input, outcome, start.elapsed())
+}
+
+fn print_records(
+    results: &[PartResult],
+    input: &InputSet,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let records = results
+        .iter()
+        .map(|r| r.to_record(input))
+        .collect::<Vec<_>>();
+    report::write_records(&mut io::stdout().lock(), format, &records)
+        .map_err(|err| format!("cannot write results - {err}"))
+}
+
+/// Fail if any part failed or got a wrong answer.
+fn check_results(results: &[PartResult]) -> Result<(), String> {
+    let n_panicked = results
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Panicked(_)))
+        .count();
+    let n_wrong = results
+        .iter()
+        .filter(|r| matches!(r.verdict, Verdict::Wrong { .. }))
+        .count();
+    let n_failed = results.iter().filter(|r| !r.outcome.is_ok()).count();
+    if n_failed > 0 || n_wrong > 0 {
+        return Err(format!(
+            "{} of {} parts failed ({n_panicked} panicked, {n_wrong} wrong)",
+            n_failed + n_wrong,
+            results.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Answers wider than this are cut short in the summary table.
+const MAX_CELL_WIDTH: usize = 60;
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let mut cut = s.chars().take(max_chars - 1).collect::<String>();
+        cut.push('…');
+        cut
+    }
+}
+
+fn run_all(
+    resolver: &InputResolver,
+    store: &AnswerStore,
+    part: Option<u8>,
+    input: InputSet,
+    format: OutputFormat,
+) -> Result<(), String> {
+    if matches!(input, InputSet::File(_) | InputSet::Stdin) {
+        return Err("--all needs an input set every day has (`real` or `example`)".to_string());
+    }
+
+    let jobs = DAYS
+        .iter()
+        .flat_map(|day| {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            parts.into_iter().map(move |part| (day, part))
+        })
+        .collect::<Vec<_>>();
+
+    // Panics are reported in the table, so keep the default hook from interleaving them on stderr.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = jobs
+        .par_iter()
+        .map(|(day, part)| run_part(resolver, store, day, *part, &input))
+        .collect::<Vec<_>>();
+    panic::set_hook(default_hook);
+
+    if format != OutputFormat::Text {
+        print_records(&results, &input, format)?;
+        return check_results(&results);
+    }
+
+    let cells = results
+        .iter()
+        .map(|r| truncate(&r.outcome.to_string(), MAX_CELL_WIDTH))
+        .collect::<Vec<_>>();
+    let answer_width = cells
+        .iter()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    println!(
+        "Day  Part  {:<answer_width$}  {:>10}  Check",
+        "Answer", "Time"
+    );
+    for (r, cell) in results.iter().zip(cells) {
+        let check = match (&r.outcome, &r.verdict) {
+            (Outcome::Solved(_), verdict) => verdict.to_string(),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {check}",
+            r.day,
+            r.part,
+            cell,
+            format!("{:.2?}", r.elapsed)
+        );
+    }
+
+    check_results(&results)
+}
+
+struct BenchOptions {
+    runs: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn bench(
+    resolver: &InputResolver,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: InputSet,
+    options: BenchOptions,
+) -> Result<(), String> {
+    if input == InputSet::Stdin {
+        return Err("cannot benchmark stdin input; save it to a file first".to_string());
+    }
+    let days = match day {
+        Some(day) => vec![find_day(day)?],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let previous = match &options.compare {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let input_key = input.to_string();
+
+    let mut results = Baseline::default();
+    let mut n_skipped = 0;
+    let mut n_invalid = 0;
+    let mut n_failed = 0;
+    let mut n_regressed = 0;
+    'days: for day in days {
+        let lines = match resolver.load(day.number, &input) {
+            Ok(lines) => lines,
+            Err(err) => {
+                println!("Day {:>2}: skipped ({err})", day.number);
+                n_skipped += 1;
+                continue;
+            }
+        };
+        for &part in parts.iter() {
+            let samples = panic::catch_unwind(|| {
+                (0..options.runs)
+                    .map(|_| (day.sample)(part, lines.clone()))
+                    .collect::<InputResult<Vec<_>>>()
+            });
+            let samples = match samples {
+                Ok(Ok(samples)) => samples,
+                Ok(Err(err)) => {
+                    // The input is the same for both parts, so neither can run.
+                    println!("Day {:>2}: skipped ({err})", day.number);
+                    n_invalid += 1;
+                    continue 'days;
+                }
+                Err(payload) => {
+                    println!(
+                        "Day {:>2} part {part}: panicked: {}",
+                        day.number,
+                        panic_message(payload)
+                    );
+                    n_failed += 1;
+                    continue;
+                }
+            };
+            let bench = PartBench::new(&samples);
+            println!(
+                "Day {:>2} part {part}: total {}, parse {}, solve {}",
+                day.number, bench.total, bench.parse, bench.solve
+            );
+            if let Some(old) = previous
+                .as_ref()
+                .and_then(|p| p.get(day.number, part, &input_key))
+            {
+                let change = bench.change_from(old) * 100.0;
+                let regressed = change > options.threshold;
+                if regressed {
+                    n_regressed += 1;
+                }
+                println!(
+                    "        median {:.2?} -> {:.2?} ({change:+.1}%){}",
+                    old.total.median(),
+                    bench.total.median(),
+                    if regressed { " SLOWER" } else { "" }
+                );
+            }
+            results.insert(day.number, part, &input_key, bench);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        results.save(path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+    if n_skipped > 0 {
+        println!("{n_skipped} days skipped for lack of input");
+    }
+    if n_invalid > 0 {
+        println!("{n_invalid} days skipped for invalid input");
+    }
+    if n_failed > 0 {
+        return Err(format!("{n_failed} parts panicked"));
+    }
+    if n_regressed > 0 {
+        return Err(format!(
+            "{n_regressed} parts are more than {}% slower than the baseline",
+            options.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn visualize(
+    resolver: &InputResolver,
+    day: u8,
+    part: u8,
+    input: InputSet,
+    save: Option<PathBuf>,
+    scale: u32,
+) -> Result<(), String> {
+    if input == InputSet::Stdin {
+        return Err("cannot visualize stdin input; save it to a file first".to_string());
+    }
+    let day = find_day(day)?;
+    let lines = resolver
+        .load(day.number, &input)
+        .map_err(|err| err.to_string())?;
+
+    visualize::start_recording();
+    let answer = (day.solve)(part, lines);
+    let frames = visualize::stop_recording();
+    let answer = answer.map_err(|err| err.to_string())?;
+    match (frames.last(), save) {
+        (None, _) => println!("Day {} part {part} does not record any frames", day.number),
+        (Some(last), Some(path)) => {
+            last.to_image(&visualize::default_palette())
+                .scaled(scale)
+                .save(&path)
+                .map_err(|err| format!("cannot save {}: {err}", path.display()))?;
+            println!("Saved {}", path.display());
+        }
+        (Some(_), None) => {
+            visualize::play(&frames).map_err(|err| format!("cannot play frames: {err}"))?
+        }
+    }
+    println!("Day {} part {part}: {answer}", day.number);
+    Ok(())
+}
+
+fn list(resolver: &InputResolver) {
+    for day in DAYS.iter() {
+        let path = resolver
+            .path(day.number, &InputSet::Real)
+            .unwrap_or_default();
+        println!("Day {:>2} (input: {})", day.number, path.display());
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match LogFilter::from_verbosity(cli.verbose).apply(cli.log.as_deref().unwrap_or_default()) {
+        Ok(filter) => logging::init(filter),
+        Err(err) => {
+            eprintln!("error: --log: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let resolver = InputResolver::from_env(cli.input_dir);
+    let mut store = match AnswerStore::load(cli.answers) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // Progress bars would trample each other and the timings when many parts run at once.
+    let batch = matches!(
+        cli.command,
+        Command::Run { all: true, .. } | Command::Bench { .. }
+    );
+    progress::set_mode(match cli.progress {
+        Some(mode) => mode,
+        None if batch => ProgressMode::Off,
+        None if io::stderr().is_terminal() => ProgressMode::Bar,
+        None => ProgressMode::Log,
+    });
+
+    let result = match cli.command {
+        Command::Run {
+            all: true,
+            part,
+            input,
+            format,
+            ..
+        } => run_all(&resolver, &store, part, input, format),
+        Command::Run {
+            day,
+            part,
+            input,
+            report: true,
+            ..
+        } => calibration_report(&resolver, day.unwrap(), part, input),
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            ..
+        } => run(&resolver, &store, day.unwrap(), part, input, format),
+        Command::Record {
+            day,
+            part,
+            input,
+            answer,
+        } => record(&resolver, &mut store, day, part, input, answer),
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+            save,
+            compare,
+            threshold,
+        } => bench(
+            &resolver,
+            day,
+            part,
+            input,
+            BenchOptions {
+                runs: runs as usize,
+                save,
+                compare,
+                threshold,
+            },
+        ),
+        Command::Visualize {
+            day,
+            part,
+            input,
+            save,
+            scale,
+        } => visualize(&resolver, day, part, input, save, scale),
+        Command::New { day } => scaffold::new_day(Path::new("src"), day),
+        Command::List => {
+            list(&resolver);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,194 @@
+use std::fs;
+use std::path::Path;
+
+/// Files copied from `src/_template` into a new day's directory.
+const TEMPLATE_FILES: [&str; 4] = ["mod.rs", "part1.rs", "part2.rs", "example.txt"];
+
+/// Create `src/dayN` from `src/_template` and register it in `src/lib.rs`.
+pub fn new_day(src_dir: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not in the calendar (1-25)"));
+    }
+    let template_dir = src_dir.join("_template");
+    let day_dir = src_dir.join(format!("day{day}"));
+    let lib_path = src_dir.join("lib.rs");
+    if !template_dir.is_dir() {
+        return Err(format!(
+            "cannot find {}; run this from the repository root",
+            template_dir.display()
+        ));
+    }
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let lib_rs = fs::read_to_string(&lib_path)
+        .map_err(|err| format!("cannot read {} - {}", lib_path.display(), err))?;
+    let lib_rs = register_day(&lib_rs, day)?;
+
+    fs::create_dir(&day_dir)
+        .map_err(|err| format!("cannot create {} - {}", day_dir.display(), err))?;
+    for file in TEMPLATE_FILES {
+        let from = template_dir.join(file);
+        let to = day_dir.join(file);
+        let text = fs::read_to_string(&from)
+            .map_err(|err| format!("cannot read {} - {}", from.display(), err))?;
+        fs::write(&to, fill_template(&text, day))
+            .map_err(|err| format!("cannot write {} - {}", to.display(), err))?;
+        println!("Created {}", to.display());
+    }
+
+    fs::write(&lib_path, lib_rs)
+        .map_err(|err| format!("cannot write {} - {}", lib_path.display(), err))?;
+    println!("Registered day {day} in {}", lib_path.display());
+    Ok(())
+}
+
+/// Replace the template's `X` placeholders with the day number.
+fn fill_template(text: &str, day: u8) -> String {
+    text.replace("day/X", &format!("day/{day}"))
+        .replace("dayX", &format!("day{day}"))
+        .replace("DayX", &format!("Day{day}"))
+}
+
+/// Add `pub mod dayN;` and a `DAYS` entry for `day` to the source of `lib.rs`, keeping both sorted.
+fn register_day(lib_rs: &str, day: u8) -> Result<String, String> {
+    let mod_line = format!("pub mod day{day};");
+    let entry = format!(
+        "    Day {{ number: {day}, solve: solve::<day{day}::Day{day}>, sample: sample::<day{day}::Day{day}> }},"
+    );
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_string).collect();
+    if lines.contains(&mod_line) {
+        return Err(format!("day {day} is already registered in lib.rs"));
+    }
+
+    // `mod` declarations are sorted by name as strings, the way rustfmt orders them.
+    let mod_name = format!("day{day}");
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line.strip_prefix("pub mod ")?.strip_suffix(';')?)))
+        .filter(|(_, name)| name.starts_with("day"))
+        .collect::<Vec<_>>();
+    let (last_mod, _) = *mods
+        .last()
+        .ok_or("cannot find the `pub mod dayN;` declarations in lib.rs")?;
+    let mod_at = mods
+        .iter()
+        .find(|(_, name)| *name > mod_name.as_str())
+        .map_or(last_mod + 1, |(i, _)| *i);
+    lines.insert(mod_at, mod_line);
+
+    // `DAYS` entries are sorted by day number.
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or("cannot find `DAYS` in lib.rs")?;
+    let days_end = lines[days_start..]
+        .iter()
+        .position(|line| line.as_str() == "];")
+        .map(|i| days_start + i)
+        .ok_or("cannot find the end of `DAYS` in lib.rs")?;
+    let entry_at = (days_start + 1..days_end)
+        .find(|&i| entry_number(&lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(days_end);
+    lines.insert(entry_at, entry);
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Ok(result)
+}
+
+fn entry_number(line: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix("Day { number: ")?;
+    rest[..rest.find(',')?].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "pub mod common;
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+pub const DAYS: &[Day] = &[
+    Day { number: 1, solve: solve::<day1::Day1>, sample: sample::<day1::Day1> },
+    Day { number: 2, solve: solve::<day2::Day2>, sample: sample::<day2::Day2> },
+    Day { number: 10, solve: solve::<day10::Day10>, sample: sample::<day10::Day10> },
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let lib_rs = register_day(LIB_RS, 3).unwrap();
+        let lines = lib_rs.lines().collect::<Vec<_>>();
+        assert_eq!(
+            &lines[1..5],
+            [
+                "pub mod day1;",
+                "pub mod day10;",
+                "pub mod day2;",
+                "pub mod day3;"
+            ]
+        );
+        assert_eq!(entry_number(lines[8]), Some(2));
+        assert_eq!(entry_number(lines[9]), Some(3));
+        assert_eq!(entry_number(lines[10]), Some(10));
+
+        let lib_rs = register_day(&lib_rs, 25).unwrap();
+        let lines = lib_rs.lines().collect::<Vec<_>>();
+        assert_eq!(
+            &lines[3..6],
+            ["pub mod day2;", "pub mod day25;", "pub mod day3;"]
+        );
+        assert_eq!(entry_number(lines[12]), Some(25));
+        assert_eq!(lines[13], "];");
+
+        assert!(register_day(&lib_rs, 3).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let src_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let template_dir = src_dir.join("_template");
+        fs::create_dir_all(&template_dir).unwrap();
+        for file in TEMPLATE_FILES {
+            fs::copy(
+                Path::new("src/_template").join(file),
+                template_dir.join(file),
+            )
+            .unwrap();
+        }
+        fs::write(src_dir.join("lib.rs"), LIB_RS).unwrap();
+
+        let result = new_day(&src_dir, 25);
+        let generated = TEMPLATE_FILES.map(|file| {
+            let template = fs::read_to_string(template_dir.join(file)).unwrap();
+            let generated = fs::read_to_string(src_dir.join("day25").join(file));
+            (fill_template(&template, 25), generated)
+        });
+        let lib_rs = fs::read_to_string(src_dir.join("lib.rs")).unwrap();
+        fs::remove_dir_all(&src_dir).unwrap();
+
+        result.unwrap();
+        // The crate's tests build the template as it is, so the generated files must differ from
+        // it only in the day number to be held to the same lints.
+        for (expected, generated) in generated {
+            assert_eq!(generated.unwrap(), expected);
+        }
+        assert!(lib_rs.contains("pub mod day25;"));
+    }
+
+    #[test]
+    fn test_fill_template() {
+        assert_eq!(
+            fill_template(
+                "// https://adventofcode.com/2023/day/X\npub struct DayX;",
+                23
+            ),
+            "// https://adventofcode.com/2023/day/23\npub struct Day23;"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Solving days through the library's public API, the way another crate would.
+
+use aoc::common::{solve_str, Solution};
+use aoc::day16::Day16;
+use aoc::day2::Day2;
+use aoc::{find_day, DAYS};
+
+#[test]
+fn test_solve_str() {
+    let example = Day2::EXAMPLES[0].input;
+    assert_eq!(solve_str::<Day2>(1, example).unwrap().to_string(), "8");
+    assert_eq!(solve_str::<Day2>(2, example).unwrap().to_string(), "2286");
+    assert_eq!(
+        solve_str::<Day16>(1, Day16::EXAMPLES[0].input)
+            .unwrap()
+            .to_string(),
+        "46"
+    );
+}
+
+#[test]
+fn test_registry() {
+    assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    let day = find_day(2).unwrap();
+    let lines = Day2::EXAMPLES[0]
+        .input
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!((day.solve)(2, lines).unwrap().to_string(), "2286");
+    assert!(find_day(25).is_none());
+}